@@ -4,8 +4,7 @@ use std::fs::File;
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
-static CDE_ALPHABET: &'static str =
-    "abcdefghijklmnopqrstuvwxyz01234-ABCDEFGHIJKLMNOPQRSTUVWXYZ56789_";
+static CDE_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz01234-ABCDEFGHIJKLMNOPQRSTUVWXYZ56789_";
 
 fn idx(c: char) -> u8 {
     if let Some(i) = CDE_ALPHABET.find(c) {
@@ -15,7 +14,7 @@ fn idx(c: char) -> u8 {
     }
 }
 
-fn main() -> Result<(), Box<(dyn Error + 'static)>> {
+fn main() -> Result<(), Box<dyn Error + 'static>> {
     let path = Path::new(&env::var("OUT_DIR").unwrap()).join("hashmaps.rs");
     let mut file = BufWriter::new(File::create(&path)?);
 
@@ -55,6 +54,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
             .entry(idx('D'), "(\"Digest\", &NAMES_DIGEST)")
             .entry(idx('E'), "(\"Encryption\", &NAMES_ENCRYPTION)")
             .entry(idx('F'), "(\"Strobe\", &NAMES_STROBE)")
+            .entry(idx('G'), "(\"Kdf\", &NAMES_KDF)")
             .entry(idx('H'), "(\"Hmac\", &NAMES_HMAC)")
             .entry(idx('I'), "(\"Identifier\", &NAMES_IDENTIFIER)")
             .entry(idx('K'), "(\"Key\", &NAMES_KEY)")
@@ -89,7 +89,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
             .entry(idx('_'), "(\"undefined\", None)")
             .entry(idx('-'), "(\"list\", None)")
             .entry(idx('o'), "(\"oberon\", None)")
-            .entry(idx('O'), "(\"oberon\", None)")
+            .entry(idx('O'), "(\"Oberon\", None)")
             .build()
     )?;
 
@@ -115,7 +115,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_DIGEST_BLAKE2: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"b\"")
             .entry(2, "\"s\"")
             .build()
@@ -124,7 +124,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_DIGEST_MD: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"5\"")
             .entry(2, "\"4\"")
             .entry(3, "\"2\"")
@@ -135,7 +135,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_DIGEST_SHA2: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"256\"")
             .entry(2, "\"512\"")
             .entry(3, "\"224\"")
@@ -148,7 +148,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_DIGEST_SHA3: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"256\"")
             .entry(2, "\"512\"")
             .entry(3, "\"224\"")
@@ -174,7 +174,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_CIPHER_AES: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"256\"")
             .entry(2, "\"128\"")
             .entry(3, "\"192\"")
@@ -207,7 +207,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_STROBE_AD: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"data\"")
             .entry(2, "\"meta\"")
             .build()
@@ -216,7 +216,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_STROBE_CLR: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"data_send\"")
             .entry(2, "\"data_recv\"")
             .entry(3, "\"meta_send\"")
@@ -227,7 +227,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_STROBE_ENC: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"data_send\"")
             .entry(2, "\"data_recv\"")
             .entry(3, "\"meta_send\"")
@@ -238,7 +238,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_STROBE_KEY: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"data\"")
             .entry(2, "\"meta\"")
             .build()
@@ -247,7 +247,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_STROBE_MAC: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"data_send\"")
             .entry(2, "\"data_recv\"")
             .entry(3, "\"meta_send\"")
@@ -258,7 +258,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_STROBE_PRF: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"data\"")
             .entry(2, "\"meta\"")
             .build()
@@ -267,7 +267,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_STROBE_RATCHET: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"data\"")
             .entry(2, "\"meta\"")
             .build()
@@ -279,6 +279,25 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
         phf_codegen::OrderedMap::new()
             .entry(idx('_'), "(\"undefined\", None)")
             .entry(idx('-'), "(\"list\", None)")
+            .entry(idx('b'), "(\"blake2\", Some(&NAMES_DIGEST_BLAKE2))")
+            .entry(idx('h'), "(\"sha2\", Some(&NAMES_DIGEST_SHA2))")
+            .entry(idx('a'), "(\"sha3\", Some(&NAMES_HMAC_SHA3))")
+            .entry(idx('B'), "(\"Blake2\", Some(&NAMES_DIGEST_BLAKE2))")
+            .entry(idx('H'), "(\"Sha2\", Some(&NAMES_DIGEST_SHA2))")
+            .entry(idx('A'), "(\"Sha3\", Some(&NAMES_HMAC_SHA3))")
+            .build()
+    )?;
+
+    // the same values as the sha3 digests without the extendable output
+    // functions which can't be used with hmac
+    writeln!(
+        &mut file,
+        "static NAMES_HMAC_SHA3: SubSubNamesMap = \n{};\n\n",
+        phf_codegen::OrderedMap::<u8>::new()
+            .entry(1, "\"256\"")
+            .entry(2, "\"512\"")
+            .entry(3, "\"224\"")
+            .entry(4, "\"384\"")
             .build()
     )?;
 
@@ -297,6 +316,25 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
             .build()
     )?;
 
+    // the key derivation functions are an experimental class until the spec
+    // standardises them
+    writeln!(
+        &mut file,
+        "static NAMES_KDF: SubNamesMap = \n{};\n\n",
+        phf_codegen::OrderedMap::new()
+            .entry(idx('_'), "(\"undefined\", None)")
+            .entry(idx('-'), "(\"list\", None)")
+            .entry(idx('a'), "(\"argon2\", None)")
+            .entry(idx('h'), "(\"hkdf\", None)")
+            .entry(idx('p'), "(\"pbkdf2\", None)")
+            .entry(idx('s'), "(\"scrypt\", None)")
+            .entry(idx('A'), "(\"Argon2\", None)")
+            .entry(idx('H'), "(\"Hkdf\", None)")
+            .entry(idx('P'), "(\"Pbkdf2\", None)")
+            .entry(idx('S'), "(\"Scrypt\", None)")
+            .build()
+    )?;
+
     writeln!(
         &mut file,
         "static NAMES_KEY: SubNamesMap = \n{};\n\n",
@@ -317,6 +355,9 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
             .entry(idx('C'), "(\"Chacha20\", None)")
             .entry(idx('E'), "(\"Ed25519\", Some(&NAMES_KEY_ED25519))")
             .entry(idx('K'), "(\"K256\", Some(&NAMES_KEY_K256))")
+            // password locked secret keys stay experimental until the spec
+            // standardises them
+            .entry(idx('L'), "(\"Locked\", None)")
             .entry(idx('P'), "(\"P256\", Some(&NAMES_KEY_P256))")
             .entry(idx('R'), "(\"Rsa\", Some(&NAMES_KEY_RSA))")
             .entry(idx('S'), "(\"Shared-secret\", Some(&NAMES_KEY_SHARED))")
@@ -327,7 +368,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_KEY_AES: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"128\"")
             .entry(2, "\"256\"")
             .build()
@@ -336,7 +377,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_KEY_BLS12381: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"public\"")
             .entry(2, "\"secret\"")
             .build()
@@ -345,7 +386,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_KEY_ED25519: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"public\"")
             .entry(2, "\"secret\"")
             .build()
@@ -354,7 +395,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_KEY_K256: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"public\"")
             .entry(2, "\"secret\"")
             .entry(3, "\"verifying\"")
@@ -365,7 +406,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_KEY_P256: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"public\"")
             .entry(2, "\"secret\"")
             .entry(3, "\"verifying\"")
@@ -376,7 +417,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_KEY_RSA: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"public\"")
             .entry(2, "\"secret\"")
             .build()
@@ -385,7 +426,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_KEY_SHARED: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"psk\"")
             .entry(2, "\"pkdh\"")
             .entry(3, "\"ecdh\"")
@@ -395,7 +436,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_KEY_X25519: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"public\"")
             .entry(2, "\"secret\"")
             .build()
@@ -418,7 +459,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_NONCE_HALFWORD: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"le\"")
             .entry(2, "\"be\"")
             .build()
@@ -427,7 +468,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_NONCE_WORD: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"le\"")
             .entry(2, "\"be\"")
             .build()
@@ -436,7 +477,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_NONCE_DOUBLEWORD: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"le\"")
             .entry(2, "\"be\"")
             .build()
@@ -445,7 +486,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
     writeln!(
         &mut file,
         "static NAMES_NONCE_QUADWORD: SubSubNamesMap = \n{};\n\n",
-        phf_codegen::OrderedMap::new()
+        phf_codegen::OrderedMap::<u8>::new()
             .entry(1, "\"le\"")
             .entry(2, "\"be\"")
             .build()
@@ -474,13 +515,25 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
             .entry(idx('o'), "(\"openssl\", None)")
             .entry(idx('p'), "(\"pgp\", None)")
             .entry(idx('x'), "(\"x509\", None)")
+            .entry(idx('C'), "(\"Ecdsa\", Some(&NAMES_SIGNATURE_ECDSA))")
+            .entry(idx('E'), "(\"Ed25519\", None)")
             .entry(idx('M'), "(\"Minisign\", None)")
             .entry(idx('O'), "(\"Openssl\", None)")
             .entry(idx('P'), "(\"Pgp\", None)")
+            .entry(idx('R'), "(\"Rsa\", None)")
             .entry(idx('X'), "(\"X509\", None)")
             .build()
     )?;
 
+    writeln!(
+        &mut file,
+        "static NAMES_SIGNATURE_ECDSA: SubSubNamesMap = \n{};\n\n",
+        phf_codegen::OrderedMap::<u8>::new()
+            .entry(1, "\"p256\"")
+            .entry(2, "\"k256\"")
+            .build()
+    )?;
+
     writeln!(
         &mut file,
         "static NAMES_TIMESTAMP: SubNamesMap = \n{};\n\n",
@@ -549,6 +602,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
             .entry("Digest", "(35, &VALUES_DIGEST)")
             .entry("Encryption", "(36, &VALUES_ENCRYPTION)")
             .entry("Strobe", "(37, &VALUES_STROBE)")
+            .entry("Kdf", "(38, &VALUES_KDF)")
             .entry("Hmac", "(39, &VALUES_HMAC)")
             .entry("Identifier", "(40, &VALUES_IDENTIFIER)")
             .entry("Key", "(42, &VALUES_KEY)")
@@ -861,7 +915,43 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
         &mut file,
         "static VALUES_HMAC: SubValuesMap = \n{};\n\n",
         phf_codegen::OrderedMap::new()
+            .entry("undefined", format!("({}, None)", idx('_')).as_str())
             .entry("list", format!("({}, None)", idx('-')).as_str())
+            .entry(
+                "blake2",
+                format!("({}, Some(&VALUES_DIGEST_BLAKE2))", idx('b')).as_str()
+            )
+            .entry(
+                "sha2",
+                format!("({}, Some(&VALUES_DIGEST_SHA2))", idx('h')).as_str()
+            )
+            .entry(
+                "sha3",
+                format!("({}, Some(&VALUES_HMAC_SHA3))", idx('a')).as_str()
+            )
+            .entry(
+                "Blake2",
+                format!("({}, Some(&VALUES_DIGEST_BLAKE2))", idx('B')).as_str()
+            )
+            .entry(
+                "Sha2",
+                format!("({}, Some(&VALUES_DIGEST_SHA2))", idx('H')).as_str()
+            )
+            .entry(
+                "Sha3",
+                format!("({}, Some(&VALUES_HMAC_SHA3))", idx('A')).as_str()
+            )
+            .build()
+    )?;
+
+    writeln!(
+        &mut file,
+        "static VALUES_HMAC_SHA3: SubSubValuesMap = \n{};\n\n",
+        phf_codegen::OrderedMap::new()
+            .entry("256", "1")
+            .entry("512", "2")
+            .entry("224", "3")
+            .entry("384", "4")
             .build()
     )?;
 
@@ -880,6 +970,23 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
             .build()
     )?;
 
+    writeln!(
+        &mut file,
+        "static VALUES_KDF: SubValuesMap = \n{};\n\n",
+        phf_codegen::OrderedMap::new()
+            .entry("undefined", format!("({}, None)", idx('_')).as_str())
+            .entry("list", format!("({}, None)", idx('-')).as_str())
+            .entry("argon2", format!("({}, None)", idx('a')).as_str())
+            .entry("hkdf", format!("({}, None)", idx('h')).as_str())
+            .entry("pbkdf2", format!("({}, None)", idx('p')).as_str())
+            .entry("scrypt", format!("({}, None)", idx('s')).as_str())
+            .entry("Argon2", format!("({}, None)", idx('A')).as_str())
+            .entry("Hkdf", format!("({}, None)", idx('H')).as_str())
+            .entry("Pbkdf2", format!("({}, None)", idx('P')).as_str())
+            .entry("Scrypt", format!("({}, None)", idx('S')).as_str())
+            .build()
+    )?;
+
     writeln!(
         &mut file,
         "static VALUES_KEY: SubValuesMap = \n{};\n\n",
@@ -936,6 +1043,7 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
                 "K256",
                 format!("({}, Some(&VALUES_KEY_K256))", idx('K')).as_str()
             )
+            .entry("Locked", format!("({}, None)", idx('L')).as_str())
             .entry(
                 "P256",
                 format!("({}, Some(&VALUES_KEY_P256))", idx('P')).as_str()
@@ -1117,13 +1225,28 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
             .entry("openssl", format!("({}, None)", idx('o')).as_str())
             .entry("pgp", format!("({}, None)", idx('p')).as_str())
             .entry("x509", format!("({}, None)", idx('x')).as_str())
+            .entry(
+                "Ecdsa",
+                format!("({}, Some(&VALUES_SIGNATURE_ECDSA))", idx('C')).as_str()
+            )
+            .entry("Ed25519", format!("({}, None)", idx('E')).as_str())
             .entry("Minisign", format!("({}, None)", idx('M')).as_str())
             .entry("Openssl", format!("({}, None)", idx('O')).as_str())
             .entry("Pgp", format!("({}, None)", idx('P')).as_str())
+            .entry("Rsa", format!("({}, None)", idx('R')).as_str())
             .entry("X509", format!("({}, None)", idx('X')).as_str())
             .build()
     )?;
 
+    writeln!(
+        &mut file,
+        "static VALUES_SIGNATURE_ECDSA: SubSubValuesMap = \n{};\n\n",
+        phf_codegen::OrderedMap::new()
+            .entry("p256", "1")
+            .entry("k256", "2")
+            .build()
+    )?;
+
     writeln!(
         &mut file,
         "static VALUES_TIMESTAMP: SubValuesMap = \n{};\n\n",
@@ -1156,5 +1279,137 @@ fn main() -> Result<(), Box<(dyn Error + 'static)>> {
             .build()
     )?;
 
+    // common shorthand names for the standard types, including the IANA, JOSE
+    // and COSE spellings. the keys are all lower case because aliases are
+    // matched ignoring case
+    writeln!(
+        &mut file,
+        "static ALIASES: phf::Map<&'static str, &'static str> = \n{};\n\n",
+        phf_codegen::Map::new()
+            .entry("sha1", "\"digest.sha1\"")
+            .entry("sha-1", "\"digest.sha1\"")
+            .entry("sha224", "\"digest.sha2.224\"")
+            .entry("sha-224", "\"digest.sha2.224\"")
+            .entry("sha2-224", "\"digest.sha2.224\"")
+            .entry("sha256", "\"digest.sha2.256\"")
+            .entry("sha-256", "\"digest.sha2.256\"")
+            .entry("sha2-256", "\"digest.sha2.256\"")
+            .entry("sha384", "\"digest.sha2.384\"")
+            .entry("sha-384", "\"digest.sha2.384\"")
+            .entry("sha2-384", "\"digest.sha2.384\"")
+            .entry("sha512", "\"digest.sha2.512\"")
+            .entry("sha-512", "\"digest.sha2.512\"")
+            .entry("sha2-512", "\"digest.sha2.512\"")
+            .entry("sha512/224", "\"digest.sha2.512/224\"")
+            .entry("sha-512/224", "\"digest.sha2.512/224\"")
+            .entry("sha512/256", "\"digest.sha2.512/256\"")
+            .entry("sha-512/256", "\"digest.sha2.512/256\"")
+            .entry("sha3-224", "\"digest.sha3.224\"")
+            .entry("sha3-256", "\"digest.sha3.256\"")
+            .entry("sha3-384", "\"digest.sha3.384\"")
+            .entry("sha3-512", "\"digest.sha3.512\"")
+            .entry("shake128", "\"digest.sha3.shake128\"")
+            .entry("shake256", "\"digest.sha3.shake256\"")
+            .entry("blake2b", "\"digest.blake2.b\"")
+            .entry("blake2s", "\"digest.blake2.s\"")
+            .entry("md2", "\"digest.md.2\"")
+            .entry("md4", "\"digest.md.4\"")
+            .entry("md5", "\"digest.md.5\"")
+            .entry("md6", "\"digest.md.6\"")
+            .entry("hmac-sha224", "\"hmac.sha2.224\"")
+            .entry("hmac-sha256", "\"hmac.sha2.256\"")
+            .entry("hmac-sha384", "\"hmac.sha2.384\"")
+            .entry("hmac-sha512", "\"hmac.sha2.512\"")
+            .entry("hs256", "\"hmac.sha2.256\"")
+            .entry("hs384", "\"hmac.sha2.384\"")
+            .entry("hs512", "\"hmac.sha2.512\"")
+            .entry("hmac-sha3-224", "\"hmac.sha3.224\"")
+            .entry("hmac-sha3-256", "\"hmac.sha3.256\"")
+            .entry("hmac-sha3-384", "\"hmac.sha3.384\"")
+            .entry("hmac-sha3-512", "\"hmac.sha3.512\"")
+            .entry("hmac-blake2b", "\"hmac.blake2.b\"")
+            .entry("hmac-blake2s", "\"hmac.blake2.s\"")
+            .entry("hkdf", "\"Kdf.hkdf\"")
+            .entry("pbkdf2", "\"Kdf.pbkdf2\"")
+            .entry("scrypt", "\"Kdf.scrypt\"")
+            .entry("argon2", "\"Kdf.argon2\"")
+            .entry("aes-256-gcm", "\"aead.aes256-gcm\"")
+            .entry("aes256gcm", "\"aead.aes256-gcm\"")
+            .entry("a256gcm", "\"aead.aes256-gcm\"")
+            .entry("chacha20poly1305", "\"aead.chacha20-poly1305\"")
+            .entry("chacha20/poly1305", "\"aead.chacha20-poly1305-ietf\"")
+            .entry("chacha20-poly1305-ietf", "\"aead.chacha20-poly1305-ietf\"")
+            .entry("xchacha20-poly1305", "\"aead.xchacha20-poly1305-ietf\"")
+            .entry("xchacha20poly1305", "\"aead.xchacha20-poly1305-ietf\"")
+            .entry("aes-128", "\"key.aes.128\"")
+            .entry("aes128", "\"key.aes.128\"")
+            .entry("aes-256", "\"key.aes.256\"")
+            .entry("aes256", "\"key.aes.256\"")
+            .entry("chacha20", "\"key.chacha20\"")
+            .entry("ed25519-pub", "\"key.ed25519.public\"")
+            .entry("ed25519-public", "\"key.ed25519.public\"")
+            .entry("ed25519-secret", "\"key.ed25519.secret\"")
+            .entry("ed25519-priv", "\"key.ed25519.secret\"")
+            .entry("ed25519-private", "\"key.ed25519.secret\"")
+            .entry("x25519-pub", "\"key.x25519.public\"")
+            .entry("x25519-public", "\"key.x25519.public\"")
+            .entry("x25519-secret", "\"key.x25519.secret\"")
+            .entry("x25519-priv", "\"key.x25519.secret\"")
+            .entry("x25519-private", "\"key.x25519.secret\"")
+            .entry("p256-pub", "\"key.p256.public\"")
+            .entry("p256-public", "\"key.p256.public\"")
+            .entry("p256-secret", "\"key.p256.secret\"")
+            .entry("p256-priv", "\"key.p256.secret\"")
+            .entry("p256-private", "\"key.p256.secret\"")
+            .entry("p-256-pub", "\"key.p256.public\"")
+            .entry("p-256-public", "\"key.p256.public\"")
+            .entry("p-256-secret", "\"key.p256.secret\"")
+            .entry("p-256-priv", "\"key.p256.secret\"")
+            .entry("p-256-private", "\"key.p256.secret\"")
+            .entry("secp256r1-pub", "\"key.p256.public\"")
+            .entry("secp256r1-public", "\"key.p256.public\"")
+            .entry("secp256r1-secret", "\"key.p256.secret\"")
+            .entry("secp256r1-priv", "\"key.p256.secret\"")
+            .entry("secp256r1-private", "\"key.p256.secret\"")
+            .entry("prime256v1-pub", "\"key.p256.public\"")
+            .entry("prime256v1-public", "\"key.p256.public\"")
+            .entry("prime256v1-secret", "\"key.p256.secret\"")
+            .entry("prime256v1-priv", "\"key.p256.secret\"")
+            .entry("prime256v1-private", "\"key.p256.secret\"")
+            .entry("k256-pub", "\"key.k256.public\"")
+            .entry("k256-public", "\"key.k256.public\"")
+            .entry("k256-secret", "\"key.k256.secret\"")
+            .entry("k256-priv", "\"key.k256.secret\"")
+            .entry("k256-private", "\"key.k256.secret\"")
+            .entry("secp256k1-pub", "\"key.k256.public\"")
+            .entry("secp256k1-public", "\"key.k256.public\"")
+            .entry("secp256k1-secret", "\"key.k256.secret\"")
+            .entry("secp256k1-priv", "\"key.k256.secret\"")
+            .entry("secp256k1-private", "\"key.k256.secret\"")
+            .entry("rsa-pub", "\"key.rsa.public\"")
+            .entry("rsa-public", "\"key.rsa.public\"")
+            .entry("rsa-secret", "\"key.rsa.secret\"")
+            .entry("rsa-priv", "\"key.rsa.secret\"")
+            .entry("rsa-private", "\"key.rsa.secret\"")
+            .entry("bls12381-pub", "\"key.bls12381.public\"")
+            .entry("bls12381-public", "\"key.bls12381.public\"")
+            .entry("bls12381-secret", "\"key.bls12381.secret\"")
+            .entry("bls12381-priv", "\"key.bls12381.secret\"")
+            .entry("bls12381-private", "\"key.bls12381.secret\"")
+            .entry("bls12-381-pub", "\"key.bls12381.public\"")
+            .entry("bls12-381-public", "\"key.bls12381.public\"")
+            .entry("bls12-381-secret", "\"key.bls12381.secret\"")
+            .entry("bls12-381-priv", "\"key.bls12381.secret\"")
+            .entry("bls12-381-private", "\"key.bls12381.secret\"")
+            .entry("nonce", "\"nonce.bytes\"")
+            .entry("did", "\"identifier.did\"")
+            .entry("email", "\"identifier.email\"")
+            .entry("eddsa", "\"signature.Ed25519\"")
+            .entry("es256", "\"signature.Ecdsa.p256\"")
+            .entry("es256k", "\"signature.Ecdsa.k256\"")
+            .entry("rs256", "\"signature.Rsa\"")
+            .build()
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,286 @@
+//! Sealing and opening data with CDE symmetric keys
+//!
+//! A `key.aes.128` or `key.aes.256` object makes an AES-GCM cipher of the
+//! same key size and a `key.chacha20` object makes a ChaCha20-Poly1305 or
+//! XChaCha20-Poly1305 cipher.
+//!
+//! A sealed message is an `aead.list` object holding three items, the
+//! `nonce.bytes` nonce, the associated data as an `undefined.undefined`
+//! object and the ciphertext with the authentication tag appended under the
+//! `aead.*` tag of the algorithm. The aes256-gcm algorithm takes a
+//! `key.aes.256` key and the chacha20-poly1305-ietf and
+//! xchacha20-poly1305-ietf algorithms take a `key.chacha20` key. The original
+//! chacha20-poly1305 with a 64-bit nonce isn't supported.
+use crate::{Error, Object, Result, Tag, TagBuilder};
+use aead::{rand_core::RngCore, Aead, AeadCore, Nonce, OsRng, Payload};
+#[cfg(feature = "aes-gcm")]
+use aes_gcm::{aead, Aes128Gcm, Aes256Gcm};
+#[cfg(not(feature = "aes-gcm"))]
+use chacha20poly1305::aead;
+#[cfg(feature = "chacha20poly1305")]
+use chacha20poly1305::{ChaCha20Poly1305, XChaCha20Poly1305};
+use std::convert::TryFrom;
+
+/// The algorithms, the features that enable them and their nonce sizes
+static ALGORITHMS: [(&str, &str, usize); 3] = [
+    ("aead.aes256-gcm", "aes-gcm", 12),
+    ("aead.chacha20-poly1305-ietf", "chacha20poly1305", 12),
+    ("aead.xchacha20-poly1305-ietf", "chacha20poly1305", 24),
+];
+
+/// A sealed message
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Envelope {
+    nonce: Vec<u8>,
+    associated_data: Vec<u8>,
+    ciphertext: Object,
+}
+
+impl Envelope {
+    /// Reads an envelope from an `aead.list` object
+    pub fn from_object(obj: &Object) -> Result<Self> {
+        if obj.tag().canonical_name() != "aead.list" {
+            return Err(Error::UnsupportedType(obj.tag().canonical_name()));
+        }
+        let items = obj.items()?;
+        let (nonce, ad, ciphertext) = match items.as_slice() {
+            [nonce, ad, ciphertext] => (nonce, ad, ciphertext),
+            _ => return Err(aead_err("expected a nonce, associated data and ciphertext")),
+        };
+        if nonce.tag().canonical_name() != "nonce.bytes" {
+            return Err(aead_err("the first item isn't a nonce.bytes object"));
+        }
+        if ad.tag().canonical_name() != "undefined.undefined" {
+            return Err(aead_err(
+                "the second item isn't an undefined.undefined object",
+            ));
+        }
+        if ciphertext.tag().class() != obj.tag().class() || ciphertext.is_list() {
+            return Err(aead_err("the third item isn't an aead.* object"));
+        }
+        Ok(Envelope {
+            nonce: nonce.data().to_vec(),
+            associated_data: ad.data().to_vec(),
+            ciphertext: ciphertext.clone(),
+        })
+    }
+
+    /// Returns the envelope as an `aead.list` object
+    pub fn to_object(&self) -> Result<Object> {
+        let items = [
+            Object::from_type("nonce.bytes", &self.nonce)?,
+            Object::from_type("undefined.undefined", &self.associated_data)?,
+            self.ciphertext.clone(),
+        ];
+        Ok(Object::list(
+            TagBuilder::from_tag("aead.list").build()?,
+            &items,
+        ))
+    }
+
+    /// Returns the tag of the algorithm that sealed the message
+    pub fn algorithm(&self) -> Tag {
+        self.ciphertext.tag()
+    }
+
+    pub fn nonce(&self) -> &[u8] {
+        &self.nonce
+    }
+
+    pub fn associated_data(&self) -> &[u8] {
+        &self.associated_data
+    }
+
+    /// Returns the ciphertext followed by the authentication tag
+    pub fn ciphertext(&self) -> &[u8] {
+        self.ciphertext.data()
+    }
+}
+
+/// Seals the plaintext and associated data with the key using the algorithm
+/// in the tag and a random nonce
+pub fn seal(key: &Object, alg: Tag, plaintext: &[u8], ad: &[u8]) -> Result<Envelope> {
+    let mut nonce = vec![0; algorithm(alg)?.2];
+    OsRng.fill_bytes(&mut nonce);
+    seal_with_nonce(key, alg, &nonce, plaintext, ad)
+}
+
+/// Seals the plaintext and associated data with the key using the algorithm
+/// in the tag and the given nonce, a nonce must never be used twice with the
+/// same key
+pub fn seal_with_nonce(
+    key: &Object,
+    alg: Tag,
+    nonce: &[u8],
+    plaintext: &[u8],
+    ad: &[u8],
+) -> Result<Envelope> {
+    let ciphertext = crypt(key, alg, Direction::Seal, nonce, plaintext, ad)?;
+    Ok(Envelope {
+        nonce: nonce.to_vec(),
+        associated_data: ad.to_vec(),
+        ciphertext: Object::new(alg, &ciphertext),
+    })
+}
+
+/// Opens the envelope with the key returning the plaintext, a ciphertext or
+/// associated data that doesn't authenticate gives a `DecryptionFailed` error
+pub fn open(key: &Object, envelope: &Envelope) -> Result<Vec<u8>> {
+    crypt(
+        key,
+        envelope.algorithm(),
+        Direction::Open,
+        &envelope.nonce,
+        envelope.ciphertext(),
+        &envelope.associated_data,
+    )
+}
+
+#[derive(Clone, Copy)]
+enum Direction {
+    Seal,
+    Open,
+}
+
+/// Looks up the algorithm in the tag
+fn algorithm(alg: Tag) -> Result<&'static (&'static str, &'static str, usize)> {
+    let name = alg.canonical_name();
+    match ALGORITHMS.iter().find(|(n, _, _)| *n == name) {
+        Some(a) => Ok(a),
+        None if name == "aead.chacha20-poly1305" => Err(Error::UnsupportedAlgorithm(
+            "chacha20-poly1305 with a 64-bit nonce".to_string(),
+        )),
+        None => Err(Error::UnsupportedType(name)),
+    }
+}
+
+/// Makes the cipher for the algorithm from the key and runs it
+fn crypt(
+    key: &Object,
+    alg: Tag,
+    dir: Direction,
+    nonce: &[u8],
+    data: &[u8],
+    ad: &[u8],
+) -> Result<Vec<u8>> {
+    let (name, feature, _) = algorithm(alg)?;
+    match *name {
+        #[cfg(feature = "aes-gcm")]
+        "aead.aes256-gcm" => run(Aes256Gcm::try_from(key)?, dir, nonce, data, ad),
+        #[cfg(feature = "chacha20poly1305")]
+        "aead.chacha20-poly1305-ietf" => {
+            run(ChaCha20Poly1305::try_from(key)?, dir, nonce, data, ad)
+        }
+        #[cfg(feature = "chacha20poly1305")]
+        "aead.xchacha20-poly1305-ietf" => {
+            run(XChaCha20Poly1305::try_from(key)?, dir, nonce, data, ad)
+        }
+        _ => Err(Error::UnsupportedAlgorithm(format!(
+            "{} needs the '{}' feature",
+            name, feature
+        ))),
+    }
+}
+
+fn run<C: Aead + AeadCore>(
+    cipher: C,
+    dir: Direction,
+    nonce: &[u8],
+    data: &[u8],
+    ad: &[u8],
+) -> Result<Vec<u8>> {
+    let mut n = Nonce::<C>::default();
+    if nonce.len() != n.len() {
+        return Err(aead_err(&format!(
+            "the nonce is {} bytes, expected {}",
+            nonce.len(),
+            n.len()
+        )));
+    }
+    n.copy_from_slice(nonce);
+    let payload = Payload { msg: data, aad: ad };
+    match dir {
+        Direction::Seal => cipher
+            .encrypt(&n, payload)
+            .map_err(|_| aead_err("encryption failed")),
+        Direction::Open => cipher
+            .decrypt(&n, payload)
+            .map_err(|_| Error::DecryptionFailed),
+    }
+}
+
+#[cfg(feature = "aes-gcm")]
+impl TryFrom<&Object> for Aes128Gcm {
+    type Error = Error;
+
+    /// Creates an AES-128-GCM cipher from a `key.aes.128` object
+    fn try_from(obj: &Object) -> Result<Self> {
+        cipher(obj, "key.aes.128", 16, aes_gcm::KeyInit::new_from_slice)
+    }
+}
+
+#[cfg(feature = "aes-gcm")]
+impl TryFrom<&Object> for Aes256Gcm {
+    type Error = Error;
+
+    /// Creates an AES-256-GCM cipher from a `key.aes.256` object
+    fn try_from(obj: &Object) -> Result<Self> {
+        cipher(obj, "key.aes.256", 32, aes_gcm::KeyInit::new_from_slice)
+    }
+}
+
+#[cfg(feature = "chacha20poly1305")]
+impl TryFrom<&Object> for ChaCha20Poly1305 {
+    type Error = Error;
+
+    /// Creates a ChaCha20-Poly1305 cipher from a `key.chacha20` object
+    fn try_from(obj: &Object) -> Result<Self> {
+        cipher(
+            obj,
+            "key.chacha20",
+            32,
+            chacha20poly1305::KeyInit::new_from_slice,
+        )
+    }
+}
+
+#[cfg(feature = "chacha20poly1305")]
+impl TryFrom<&Object> for XChaCha20Poly1305 {
+    type Error = Error;
+
+    /// Creates an XChaCha20-Poly1305 cipher from a `key.chacha20` object
+    fn try_from(obj: &Object) -> Result<Self> {
+        cipher(
+            obj,
+            "key.chacha20",
+            32,
+            chacha20poly1305::KeyInit::new_from_slice,
+        )
+    }
+}
+
+/// Checks the key type and size before making the cipher
+fn cipher<C, E>(
+    obj: &Object,
+    name: &str,
+    len: usize,
+    new: fn(&[u8]) -> core::result::Result<C, E>,
+) -> Result<C> {
+    if obj.tag().canonical_name() != name {
+        return Err(Error::UnsupportedType(obj.tag().canonical_name()));
+    }
+    if obj.data().len() != len {
+        return Err(Error::InvalidKey {
+            name: name.to_string(),
+            reason: "wrong key size".to_string(),
+        });
+    }
+    new(obj.data()).map_err(|_| Error::InvalidKey {
+        name: name.to_string(),
+        reason: "wrong key size".to_string(),
+    })
+}
+
+fn aead_err(reason: &str) -> Error {
+    Error::AeadError(reason.to_string())
+}
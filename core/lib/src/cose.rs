@@ -0,0 +1,256 @@
+//! Conversion between CDE keys and COSE_Key CBOR maps (RFC 9052 and RFC 9053)
+//!
+//! The ed25519 and x25519 keys are "OKP" keys, the p256 and k256 keys are
+//! "EC2" keys and the aes keys are "Symmetric" keys. The p256 and k256
+//! verifying and signing keys carry the ES256 and ES256K algorithms, ed25519
+//! keys always carry EdDSA and the aes keys carry the AES-GCM algorithm for
+//! their size. A compressed EC point is written with the sign bit of y as a
+//! bool, the way RFC 9053 allows. Secret keys can be exported without their
+//! public key because it is optional in a COSE_Key.
+use crate::{
+    idx,
+    key::{self, Algorithm, Half},
+    Error, Object, Result, Tag,
+};
+use ciborium::value::Value;
+use core::fmt::Display;
+use std::convert::TryFrom;
+
+// COSE_Key labels
+const KTY: i64 = 1;
+const ALG: i64 = 3;
+const CRV: i64 = -1;
+const K: i64 = -1;
+const X: i64 = -2;
+const Y: i64 = -3;
+const D: i64 = -4;
+
+// key types
+const OKP: i64 = 1;
+const EC2: i64 = 2;
+const SYMMETRIC: i64 = 4;
+
+/// The key type, curve and signature algorithm for each key algorithm
+static CURVES: [(Algorithm, i64, i64, Option<i64>); 4] = [
+    (Algorithm::Ed25519, OKP, 6, Some(-8)),
+    (Algorithm::X25519, OKP, 4, None),
+    (Algorithm::P256, EC2, 1, Some(-7)),
+    (Algorithm::K256, EC2, 8, Some(-47)),
+];
+
+/// The aes key sizes and their AES-GCM algorithm, indexed by sub-sub-class
+static AES: [(&str, usize, i64); 2] = [("key.aes.128", 16, 1), ("key.aes.256", 32, 3)];
+
+/// Exports a public, secret or aes key as a COSE_Key
+pub fn to_cose_key(obj: &Object) -> Result<Vec<u8>> {
+    if aes(&obj.tag()).is_some() {
+        return encode(symmetric(obj)?);
+    }
+    match key::check(obj)? {
+        (_, Half::Public) => encode(asymmetric(Some(obj), None)?),
+        (_, Half::Secret) => encode(asymmetric(None, Some(obj))?),
+    }
+}
+
+/// Exports a secret key and its public key as a COSE_Key
+pub fn secret_to_cose_key(secret: &Object, public: &Object) -> Result<Vec<u8>> {
+    encode(asymmetric(Some(public), Some(secret))?)
+}
+
+/// Imports the secret key from a COSE_Key if it has one, otherwise the public
+/// or aes key
+pub fn from_cose_key(b: &[u8]) -> Result<Object> {
+    match keys(b)? {
+        (Some(secret), _) | (None, Some(secret)) => Ok(secret),
+        (None, None) => Err(Error::CoseError("no key".to_string())),
+    }
+}
+
+/// Imports the public key from a COSE_Key
+pub fn public_from_cose_key(b: &[u8]) -> Result<Object> {
+    match keys(b)? {
+        (_, Some(public)) if aes(&public.tag()).is_none() => Ok(public),
+        _ => Err(Error::CoseError("no public key".to_string())),
+    }
+}
+
+fn symmetric(obj: &Object) -> Result<Vec<(i64, Value)>> {
+    let (len, alg) = aes(&obj.tag()).unwrap_or_default();
+    if obj.data().len() != len {
+        return Err(key::invalid(obj.tag(), "wrong key size"));
+    }
+    Ok(vec![
+        (KTY, int(SYMMETRIC)),
+        (ALG, int(alg)),
+        (K, Value::Bytes(obj.data().to_vec())),
+    ])
+}
+
+fn asymmetric(public: Option<&Object>, secret: Option<&Object>) -> Result<Vec<(i64, Value)>> {
+    let mut found = None;
+    for (obj, half) in [(public, Half::Public), (secret, Half::Secret)].iter() {
+        if let Some(obj) = obj {
+            let (alg, h) = key::check(obj)?;
+            if h != *half {
+                return Err(key::invalid(obj.tag(), "wrong half of the key pair"));
+            }
+            let this = (alg, signing(obj.tag()));
+            if *found.get_or_insert(this) != this {
+                return Err(key::invalid(obj.tag(), "doesn't match the public key type"));
+            }
+        }
+    }
+    let (alg, signing) = found.ok_or_else(|| Error::CoseError("no key".to_string()))?;
+    let (_, kty, crv, sig) = CURVES
+        .iter()
+        .find(|c| c.0 == alg)
+        .copied()
+        .ok_or_else(|| Error::UnsupportedAlgorithm("rsa COSE_Key".to_string()))?;
+
+    let mut map = vec![(KTY, int(kty))];
+    // only the ed25519 keys and the ec signing keys are tied to an algorithm
+    match sig {
+        Some(sig) if alg == Algorithm::Ed25519 || signing => map.push((ALG, int(sig))),
+        _ => {}
+    }
+    map.push((CRV, int(crv)));
+    if let Some(public) = public {
+        let point = public.data();
+        match (kty, point.len()) {
+            (EC2, 65) => {
+                map.push((X, Value::Bytes(point[1..33].to_vec())));
+                map.push((Y, Value::Bytes(point[33..].to_vec())));
+            }
+            (EC2, _) => {
+                map.push((X, Value::Bytes(point[1..].to_vec())));
+                map.push((Y, Value::Bool(point[0] == 3)));
+            }
+            _ => map.push((X, Value::Bytes(point.to_vec()))),
+        }
+    }
+    if let Some(secret) = secret {
+        map.push((D, Value::Bytes(secret.data().to_vec())));
+    }
+    Ok(map)
+}
+
+/// Decodes a COSE_Key into its secret key and its public or aes key
+fn keys(b: &[u8]) -> Result<(Option<Object>, Option<Object>)> {
+    let value: Value = ciborium::de::from_reader(b).map_err(cose_err)?;
+    let map = value
+        .as_map()
+        .ok_or_else(|| Error::CoseError("not a map".to_string()))?;
+    // labels that aren't integers belong to other specs
+    let get = |label: i64| {
+        map.iter()
+            .find(|(k, _)| k.as_integer().map(i128::from) == Some(label.into()))
+            .map(|(_, v)| v)
+    };
+    let kty = get(KTY)
+        .and_then(integer)
+        .ok_or_else(|| Error::CoseError("missing kty".to_string()))?;
+    let alg = get(ALG)
+        .map(|v| integer(v).ok_or_else(|| Error::CoseError("alg is not an integer".to_string())))
+        .transpose()?;
+
+    if kty == SYMMETRIC {
+        let k = get(K)
+            .and_then(Value::as_bytes)
+            .ok_or_else(|| Error::CoseError("missing k".to_string()))?;
+        let ssc = AES.iter().position(|a| a.1 == k.len()).ok_or_else(|| {
+            Error::UnsupportedAlgorithm(format!("{} byte symmetric key", k.len()))
+        })?;
+        if alg.map_or(false, |a| a != AES[ssc].2) {
+            return Err(unsupported_alg(alg));
+        }
+        return Ok((None, Some(Object::from_type(AES[ssc].0, k)?)));
+    }
+
+    let crv = get(CRV).and_then(integer);
+    let (algorithm, _, _, sig) = CURVES
+        .iter()
+        .find(|c| c.1 == kty && Some(c.2) == crv)
+        .copied()
+        .ok_or_else(|| match crv {
+            Some(crv) => Error::UnsupportedAlgorithm(format!("curve {}", crv)),
+            None => Error::UnsupportedAlgorithm(format!("key type {}", kty)),
+        })?;
+    if alg.is_some() && alg != sig {
+        return Err(unsupported_alg(alg));
+    }
+    // the ec keys with a signature algorithm are verifying and signing keys
+    let tag = |half: Half| {
+        let t = algorithm.tag(half);
+        if alg.is_some() && kty == EC2 {
+            t.with_subsubclass(t.subsubclass() + 2).unwrap_or(t)
+        } else {
+            t
+        }
+    };
+
+    let public = match get(X) {
+        None => None,
+        Some(x) => {
+            let x = x
+                .as_bytes()
+                .ok_or_else(|| Error::CoseError("x is not a byte string".to_string()))?;
+            let point = match (kty, get(Y)) {
+                (OKP, _) => x.to_vec(),
+                (_, Some(Value::Bytes(y))) => [&[4u8][..], x, y].concat(),
+                (_, Some(Value::Bool(odd))) => [&[2u8 + *odd as u8][..], x].concat(),
+                _ => return Err(Error::CoseError("missing y".to_string())),
+            };
+            let public = Object::new(tag(Half::Public), &point);
+            key::check(&public)?;
+            Some(public)
+        }
+    };
+    let secret = match get(D) {
+        None => None,
+        Some(d) => {
+            let d = d
+                .as_bytes()
+                .ok_or_else(|| Error::CoseError("d is not a byte string".to_string()))?;
+            let secret = Object::new(tag(Half::Secret), d);
+            key::check(&secret)?;
+            Some(secret)
+        }
+    };
+    Ok((secret, public))
+}
+
+/// Returns true for the p256 and k256 verifying and signing keys
+fn signing(tag: Tag) -> bool {
+    (tag.subclass() == idx('p') || tag.subclass() == idx('k')) && tag.subsubclass() >= 2
+}
+
+/// Returns the key size and AES-GCM algorithm for aes key tags
+fn aes(tag: &Tag) -> Option<(usize, i64)> {
+    if tag.class() != idx('k') || tag.subclass() != idx('a') {
+        return None;
+    }
+    AES.get(tag.subsubclass() as usize).map(|a| (a.1, a.2))
+}
+
+fn encode(map: Vec<(i64, Value)>) -> Result<Vec<u8>> {
+    let map = map.into_iter().map(|(k, v)| (int(k), v)).collect();
+    let mut b = Vec::new();
+    ciborium::ser::into_writer(&Value::Map(map), &mut b).map_err(cose_err)?;
+    Ok(b)
+}
+
+fn int(i: i64) -> Value {
+    Value::Integer(i.into())
+}
+
+fn integer(v: &Value) -> Option<i64> {
+    v.as_integer().and_then(|i| i64::try_from(i).ok())
+}
+
+fn unsupported_alg(alg: Option<i64>) -> Error {
+    Error::UnsupportedAlgorithm(format!("alg {}", alg.unwrap_or_default()))
+}
+
+fn cose_err<E: Display>(e: E) -> Error {
+    Error::CoseError(e.to_string())
+}
@@ -0,0 +1,186 @@
+//! Conversion between CDE keys and PKCS#8 `PrivateKeyInfo` and X.509
+//! `SubjectPublicKeyInfo` DER
+//!
+//! The ed25519 and x25519 keys are the raw 32 byte keys from RFC 8410. The
+//! p256 and k256 secret keys are the 32 byte scalar and the public keys are
+//! SEC1 encoded points. The rsa keys are the PKCS#1 `RSAPrivateKey` and
+//! `RSAPublicKey` DER.
+use crate::{
+    key::{self, Algorithm, Half},
+    Error, Object, Result,
+};
+use core::fmt::Display;
+use pkcs1::{RsaPrivateKey, RsaPublicKey};
+use pkcs8::{
+    der::{
+        asn1::{AnyRef, BitStringRef, ObjectIdentifier, OctetStringRef},
+        Decode, Encode,
+    },
+    spki::{AlgorithmIdentifierRef, SubjectPublicKeyInfoRef},
+    PrivateKeyInfo,
+};
+use sec1::EcPrivateKey;
+
+pub(crate) static ED25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+pub(crate) static X25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.110");
+pub(crate) static EC_PUBLIC_KEY: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+pub(crate) static PRIME256V1: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+pub(crate) static SECP256K1: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.10");
+pub(crate) static RSA_ENCRYPTION: ObjectIdentifier =
+    ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+
+/// Exports a secret key as PKCS#8 `PrivateKeyInfo` DER
+pub fn to_pkcs8(obj: &Object) -> Result<Vec<u8>> {
+    let (alg, half) = key::check(obj)?;
+    if half != Half::Secret {
+        return Err(key::invalid(obj.tag(), "not a secret key"));
+    }
+    let key = obj.data();
+    let inner = match alg {
+        Algorithm::Ed25519 | Algorithm::X25519 => OctetStringRef::new(key).and_then(|k| k.to_der()),
+        Algorithm::P256 | Algorithm::K256 => EcPrivateKey {
+            private_key: key,
+            parameters: None,
+            public_key: None,
+        }
+        .to_der(),
+        Algorithm::Rsa => {
+            RsaPrivateKey::from_der(key).map_err(|_| key::invalid(obj.tag(), "not PKCS#1"))?;
+            Ok(key.to_vec())
+        }
+    }
+    .map_err(der_err)?;
+    PrivateKeyInfo::new(algorithm_id(alg), &inner)
+        .to_der()
+        .map_err(der_err)
+}
+
+/// Imports a secret key from PKCS#8 `PrivateKeyInfo` DER
+pub fn from_pkcs8(der: &[u8]) -> Result<Object> {
+    let info = PrivateKeyInfo::from_der(der).map_err(der_err)?;
+    let alg = identify(&info.algorithm)?;
+    let tag = alg.tag(Half::Secret);
+    let key = match alg {
+        Algorithm::Ed25519 | Algorithm::X25519 => OctetStringRef::from_der(info.private_key)
+            .map_err(der_err)?
+            .as_bytes()
+            .to_vec(),
+        Algorithm::P256 | Algorithm::K256 => {
+            let ec = EcPrivateKey::from_der(info.private_key).map_err(der_err)?;
+            if let Some(curve) = ec.parameters.and_then(|p| p.named_curve()) {
+                if Some(curve) != named_curve(alg) {
+                    return Err(key::invalid(tag, "curve doesn't match the algorithm"));
+                }
+            }
+            // the scalar may have had its leading zeros stripped
+            if ec.private_key.len() > 32 {
+                return Err(key::invalid(tag, "wrong key size"));
+            }
+            let mut key = vec![0u8; 32 - ec.private_key.len()];
+            key.extend_from_slice(ec.private_key);
+            key
+        }
+        Algorithm::Rsa => {
+            RsaPrivateKey::from_der(info.private_key).map_err(der_err)?;
+            info.private_key.to_vec()
+        }
+    };
+    let obj = Object::new(tag, &key);
+    key::check(&obj)?;
+    Ok(obj)
+}
+
+/// Exports a public key as X.509 `SubjectPublicKeyInfo` DER
+pub fn to_spki(obj: &Object) -> Result<Vec<u8>> {
+    let (alg, half) = key::check(obj)?;
+    if half != Half::Public {
+        return Err(key::invalid(obj.tag(), "not a public key"));
+    }
+    if alg == Algorithm::Rsa {
+        RsaPublicKey::from_der(obj.data()).map_err(|_| key::invalid(obj.tag(), "not PKCS#1"))?;
+    }
+    SubjectPublicKeyInfoRef {
+        algorithm: algorithm_id(alg),
+        subject_public_key: BitStringRef::from_bytes(obj.data()).map_err(der_err)?,
+    }
+    .to_der()
+    .map_err(der_err)
+}
+
+/// Imports a public key from X.509 `SubjectPublicKeyInfo` DER
+pub fn from_spki(der: &[u8]) -> Result<Object> {
+    let spki = SubjectPublicKeyInfoRef::from_der(der).map_err(der_err)?;
+    let alg = identify(&spki.algorithm)?;
+    let key = spki
+        .subject_public_key
+        .as_bytes()
+        .ok_or_else(|| Error::DerError("public key has unused bits".to_string()))?;
+    if alg == Algorithm::Rsa {
+        RsaPublicKey::from_der(key).map_err(der_err)?;
+    }
+    let obj = Object::new(alg.tag(Half::Public), key);
+    key::check(&obj)?;
+    Ok(obj)
+}
+
+/// Exports a key as PKCS#8 if it is a secret key or `SubjectPublicKeyInfo`
+/// if it is a public key
+pub fn to_der(obj: &Object) -> Result<Vec<u8>> {
+    match key::check(obj)? {
+        (_, Half::Secret) => to_pkcs8(obj),
+        (_, Half::Public) => to_spki(obj),
+    }
+}
+
+/// Imports a key from either PKCS#8 or `SubjectPublicKeyInfo` DER
+pub fn from_der(der: &[u8]) -> Result<Object> {
+    from_pkcs8(der).or_else(|_| from_spki(der))
+}
+
+pub(crate) fn named_curve(alg: Algorithm) -> Option<ObjectIdentifier> {
+    match alg {
+        Algorithm::P256 => Some(PRIME256V1),
+        Algorithm::K256 => Some(SECP256K1),
+        _ => None,
+    }
+}
+
+/// Returns the algorithm identifier for keys of the algorithm
+pub(crate) fn algorithm_id(alg: Algorithm) -> AlgorithmIdentifierRef<'static> {
+    let (oid, parameters) = match alg {
+        Algorithm::Ed25519 => (ED25519, None),
+        Algorithm::X25519 => (X25519, None),
+        Algorithm::P256 => (EC_PUBLIC_KEY, Some(AnyRef::from(&PRIME256V1))),
+        Algorithm::K256 => (EC_PUBLIC_KEY, Some(AnyRef::from(&SECP256K1))),
+        Algorithm::Rsa => (RSA_ENCRYPTION, Some(AnyRef::NULL)),
+    };
+    AlgorithmIdentifierRef { oid, parameters }
+}
+
+/// Returns the algorithm for an algorithm identifier
+pub(crate) fn identify(id: &AlgorithmIdentifierRef<'_>) -> Result<Algorithm> {
+    if id.oid == ED25519 {
+        Ok(Algorithm::Ed25519)
+    } else if id.oid == X25519 {
+        Ok(Algorithm::X25519)
+    } else if id.oid == RSA_ENCRYPTION {
+        Ok(Algorithm::Rsa)
+    } else if id.oid == EC_PUBLIC_KEY {
+        let curve = id
+            .parameters_oid()
+            .map_err(|_| Error::UnsupportedAlgorithm("EC key without a named curve".to_string()))?;
+        match curve {
+            c if c == PRIME256V1 => Ok(Algorithm::P256),
+            c if c == SECP256K1 => Ok(Algorithm::K256),
+            c => Err(Error::UnsupportedAlgorithm(format!("curve {}", c))),
+        }
+    } else {
+        Err(Error::UnsupportedAlgorithm(format!("OID {}", id.oid)))
+    }
+}
+
+pub(crate) fn der_err<E: Display>(e: E) -> Error {
+    Error::DerError(e.to_string())
+}
@@ -0,0 +1,83 @@
+//! `did:key` identifiers for CDE public keys
+//!
+//! The method specific identifier is the multicodec-prefixed public key in
+//! base58btc with the multibase 'z' prefix. The p256 and k256 keys are always
+//! written as compressed points, as the `did:key` spec requires, so resolving
+//! one gives back the compressed form of the key.
+use crate::{multicodec, Error, Object, Result};
+
+/// The prefix of every `did:key` identifier
+pub const PREFIX: &str = "did:key:";
+
+/// The multibase prefix for base58btc
+const BASE58BTC: char = 'z';
+
+/// The key types that have a `did:key` form
+static KEYS: [&str; 5] = [
+    "key.ed25519.public",
+    "key.x25519.public",
+    "key.p256.public",
+    "key.k256.public",
+    "key.bls12381.public",
+];
+
+/// Returns the `did:key` identifier for the public key
+pub fn to_did(public: &Object) -> Result<String> {
+    let name = public.tag().canonical_name();
+    if !KEYS.contains(&name.as_str()) {
+        return Err(Error::UnsupportedType(name));
+    }
+    let key = match public.data() {
+        // compress the point, the prefix is 2 for an even y and 3 for an odd y
+        [4, point @ ..] if point.len() == 64 => {
+            let mut key = vec![2 + (point[63] & 1)];
+            key.extend_from_slice(&point[..32]);
+            Object::new(public.tag(), &key)
+        }
+        _ => public.clone(),
+    };
+    Ok(format!(
+        "{}{}{}",
+        PREFIX,
+        BASE58BTC,
+        bs58::encode(multicodec::encode(&key)?).into_string()
+    ))
+}
+
+/// Resolves a `did:key` identifier to the public key, any fragment after a
+/// '#' is ignored
+pub fn from_did(did: &str) -> Result<Object> {
+    let id = did
+        .split('#')
+        .next()
+        .and_then(|d| d.strip_prefix(PREFIX))
+        .ok_or_else(|| Error::InvalidDid(format!("'{}' is not a did:key", did)))?;
+    let encoded = id
+        .strip_prefix(BASE58BTC)
+        .ok_or_else(|| Error::InvalidDid("the key isn't base58btc".to_string()))?;
+    let b = bs58::decode(encoded)
+        .into_vec()
+        .map_err(|e| Error::InvalidDid(e.to_string()))?;
+    let public = multicodec::decode(&b)?;
+    let name = public.tag().canonical_name();
+    if !KEYS.contains(&name.as_str()) {
+        return Err(Error::UnsupportedType(name));
+    }
+    Ok(public)
+}
+
+/// Returns the `did:key` identifier for the public key as an `identifier.did`
+/// object
+pub fn to_did_object(public: &Object) -> Result<Object> {
+    Object::from_type("identifier.did", to_did(public)?.as_bytes())
+}
+
+/// Resolves an `identifier.did` object holding a `did:key` to the public key
+pub fn from_did_object(obj: &Object) -> Result<Object> {
+    if obj.tag().canonical_name() != "identifier.did" {
+        return Err(Error::UnsupportedType(obj.tag().canonical_name()));
+    }
+    let did =
+        core::str::from_utf8(obj.data()).map_err(|_| Error::InvalidDid("not utf-8".to_string()))?;
+    from_did(did)
+}
@@ -0,0 +1,93 @@
+//! Hashing data into `digest.*` objects
+//!
+//! Every digest in the registry except md6 is supported. The blake2 digests
+//! are the full size blake2b-512 and blake2s-256 and the shake128 and shake256
+//! digests are 32 and 64 bytes, the same sizes the multihash codes use.
+use crate::{Error, Object, Result, Tag};
+use blake2::{Blake2b512, Blake2s256};
+use md2::Md2;
+use md4::Md4;
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{digest::DynDigest, Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
+use sha3::{
+    digest::{ExtendableOutput, Update},
+    Sha3_224, Sha3_256, Sha3_384, Sha3_512, Shake128, Shake256,
+};
+
+/// An incremental hash of data into a `digest.*` object
+pub struct Hasher {
+    tag: Tag,
+    state: State,
+}
+
+enum State {
+    Fixed(Box<dyn DynDigest>),
+    Shake128(Shake128),
+    Shake256(Shake256),
+}
+
+impl Hasher {
+    /// Starts a hash of the digest type in the tag
+    pub fn new(tag: Tag) -> Result<Self> {
+        let name = tag.canonical_name();
+        let state = match name.as_str() {
+            "digest.sha1" => State::Fixed(Box::new(Sha1::default())),
+            "digest.sha2.224" => State::Fixed(Box::new(Sha224::default())),
+            "digest.sha2.256" => State::Fixed(Box::new(Sha256::default())),
+            "digest.sha2.384" => State::Fixed(Box::new(Sha384::default())),
+            "digest.sha2.512" => State::Fixed(Box::new(Sha512::default())),
+            "digest.sha2.512/224" => State::Fixed(Box::new(Sha512_224::default())),
+            "digest.sha2.512/256" => State::Fixed(Box::new(Sha512_256::default())),
+            "digest.sha3.224" => State::Fixed(Box::new(Sha3_224::default())),
+            "digest.sha3.256" => State::Fixed(Box::new(Sha3_256::default())),
+            "digest.sha3.384" => State::Fixed(Box::new(Sha3_384::default())),
+            "digest.sha3.512" => State::Fixed(Box::new(Sha3_512::default())),
+            "digest.sha3.shake128" => State::Shake128(Shake128::default()),
+            "digest.sha3.shake256" => State::Shake256(Shake256::default()),
+            "digest.blake2.b" => State::Fixed(Box::new(Blake2b512::default())),
+            "digest.blake2.s" => State::Fixed(Box::new(Blake2s256::default())),
+            "digest.md.2" => State::Fixed(Box::new(Md2::default())),
+            "digest.md.4" => State::Fixed(Box::new(Md4::default())),
+            "digest.md.5" => State::Fixed(Box::new(Md5::default())),
+            "digest.md.6" => return Err(Error::UnsupportedAlgorithm(name)),
+            _ => return Err(Error::UnsupportedType(name)),
+        };
+        Ok(Hasher { tag, state })
+    }
+
+    /// Adds data to the hash
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.state {
+            State::Fixed(h) => h.update(data),
+            State::Shake128(h) => Update::update(h, data),
+            State::Shake256(h) => Update::update(h, data),
+        }
+    }
+
+    /// Returns the digest of all the data as a `digest.*` object
+    pub fn finalize(self) -> Object {
+        let digest = match self.state {
+            State::Fixed(h) => h.finalize().to_vec(),
+            State::Shake128(h) => h.finalize_boxed(32).to_vec(),
+            State::Shake256(h) => h.finalize_boxed(64).to_vec(),
+        };
+        Object::new(self.tag, &digest)
+    }
+}
+
+/// Hashes the data into a `digest.*` object of the type in the tag
+pub fn digest(tag: Tag, data: &[u8]) -> Result<Object> {
+    let mut h = Hasher::new(tag)?;
+    h.update(data);
+    Ok(h.finalize())
+}
+
+/// Hashes the data again with the type of the expected digest and checks that
+/// the digests match, a different digest gives a `DigestMismatch` error
+pub fn check(expected: &Object, data: &[u8]) -> Result<()> {
+    if digest(expected.tag(), data)?.data() != expected.data() {
+        return Err(Error::DigestMismatch(expected.tag().canonical_name()));
+    }
+    Ok(())
+}
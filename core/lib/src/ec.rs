@@ -0,0 +1,89 @@
+//! Conversions between CDE p256 and k256 keys and the RustCrypto key types
+//!
+//! The `key.<curve>.secret` and `key.<curve>.public` objects are the
+//! `SecretKey` and `PublicKey` types and the `key.<curve>.signing` and
+//! `key.<curve>.verifying` objects are the ECDSA `SigningKey` and
+//! `VerifyingKey` types. Either tag of a half converts to either type. The
+//! scalar must be in range and the point must be on the curve, public keys
+//! are always written as uncompressed points.
+use crate::{
+    key::{self, Algorithm, Half},
+    Error, Object, Result,
+};
+use std::convert::TryFrom;
+
+macro_rules! conversions {
+    ($curve:ident, $alg:expr) => {
+        impl TryFrom<&Object> for $curve::SecretKey {
+            type Error = Error;
+
+            fn try_from(obj: &Object) -> Result<Self> {
+                key::expect(obj, $alg, Half::Secret)?;
+                $curve::SecretKey::from_slice(obj.data())
+                    .map_err(|_| key::invalid(obj.tag(), "scalar out of range"))
+            }
+        }
+
+        impl TryFrom<&Object> for $curve::PublicKey {
+            type Error = Error;
+
+            fn try_from(obj: &Object) -> Result<Self> {
+                key::expect(obj, $alg, Half::Public)?;
+                $curve::PublicKey::from_sec1_bytes(obj.data())
+                    .map_err(|_| key::invalid(obj.tag(), "not a point on the curve"))
+            }
+        }
+
+        impl TryFrom<&Object> for $curve::ecdsa::SigningKey {
+            type Error = Error;
+
+            fn try_from(obj: &Object) -> Result<Self> {
+                $curve::SecretKey::try_from(obj).map(Self::from)
+            }
+        }
+
+        impl TryFrom<&Object> for $curve::ecdsa::VerifyingKey {
+            type Error = Error;
+
+            fn try_from(obj: &Object) -> Result<Self> {
+                $curve::PublicKey::try_from(obj).map(Self::from)
+            }
+        }
+
+        impl From<&$curve::SecretKey> for Object {
+            fn from(key: &$curve::SecretKey) -> Self {
+                Object::new($alg.tag(Half::Secret), &key.to_bytes())
+            }
+        }
+
+        impl From<&$curve::PublicKey> for Object {
+            fn from(key: &$curve::PublicKey) -> Self {
+                use $curve::elliptic_curve::sec1::ToEncodedPoint;
+                Object::new(
+                    $alg.tag(Half::Public),
+                    key.to_encoded_point(false).as_bytes(),
+                )
+            }
+        }
+
+        impl From<&$curve::ecdsa::SigningKey> for Object {
+            fn from(key: &$curve::ecdsa::SigningKey) -> Self {
+                Object::new($alg.ecdsa_tag(Half::Secret), &key.to_bytes())
+            }
+        }
+
+        impl From<&$curve::ecdsa::VerifyingKey> for Object {
+            fn from(key: &$curve::ecdsa::VerifyingKey) -> Self {
+                Object::new(
+                    $alg.ecdsa_tag(Half::Public),
+                    key.to_encoded_point(false).as_bytes(),
+                )
+            }
+        }
+    };
+}
+
+#[cfg(feature = "k256")]
+conversions!(k256, Algorithm::K256);
+#[cfg(feature = "p256")]
+conversions!(p256, Algorithm::P256);
@@ -0,0 +1,45 @@
+//! Conversions between CDE ed25519 keys and the ed25519-dalek key types
+//!
+//! A `key.ed25519.secret` object is a `SigningKey` and a `key.ed25519.public`
+//! object is a `VerifyingKey`. The public key must be a valid curve point.
+use crate::{
+    key::{self, Algorithm, Half},
+    Error, Object, Result,
+};
+use ed25519_dalek::{SigningKey, VerifyingKey};
+use std::convert::TryFrom;
+
+impl TryFrom<&Object> for SigningKey {
+    type Error = Error;
+
+    /// Creates a signing key from a `key.ed25519.secret` object
+    fn try_from(obj: &Object) -> Result<Self> {
+        key::expect(obj, Algorithm::Ed25519, Half::Secret)?;
+        Ok(SigningKey::from_bytes(&key::bytes(obj)?))
+    }
+}
+
+impl TryFrom<&Object> for VerifyingKey {
+    type Error = Error;
+
+    /// Creates a verifying key from a `key.ed25519.public` object
+    fn try_from(obj: &Object) -> Result<Self> {
+        key::expect(obj, Algorithm::Ed25519, Half::Public)?;
+        VerifyingKey::from_bytes(&key::bytes(obj)?)
+            .map_err(|e| key::invalid(obj.tag(), &e.to_string()))
+    }
+}
+
+impl From<&SigningKey> for Object {
+    /// Creates a `key.ed25519.secret` object from a signing key
+    fn from(key: &SigningKey) -> Self {
+        Object::new(Algorithm::Ed25519.tag(Half::Secret), &key.to_bytes())
+    }
+}
+
+impl From<&VerifyingKey> for Object {
+    /// Creates a `key.ed25519.public` object from a verifying key
+    fn from(key: &VerifyingKey) -> Self {
+        Object::new(Algorithm::Ed25519.tag(Half::Public), key.as_bytes())
+    }
+}
@@ -0,0 +1,78 @@
+//! Keyed hashing of data into `hmac.*` objects
+//!
+//! The HMAC key is the data of a symmetric `key.*` object, one of
+//! `key.undefined`, `key.aes.*` or `key.chacha20`, and the hash comes
+//! from the `hmac.*` tag, one of the sha2, sha3 or blake2 digests. Checking an
+//! HMAC compares the expected and computed values in constant time.
+use crate::{Error, Object, Result, Tag};
+use ::hmac::{digest::KeyInit, Hmac, Mac, SimpleHmac};
+use blake2::{Blake2b512, Blake2s256};
+use sha2::{Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+
+/// Computes the HMAC of the data with the key, the type of HMAC comes from the
+/// tag
+pub fn hmac(tag: Tag, key: &Object, data: &[u8]) -> Result<Object> {
+    let mac = dispatch(tag, key, data, Mode::Compute)?;
+    Ok(Object::new(tag, &mac))
+}
+
+/// Computes the HMAC of the data with the key and the type of the expected HMAC
+/// and checks that they match in constant time, a different HMAC gives an
+/// `HmacMismatch` error
+pub fn verify(expected: &Object, key: &Object, data: &[u8]) -> Result<()> {
+    dispatch(expected.tag(), key, data, Mode::Verify(expected.data())).map(|_| ())
+}
+
+#[derive(Clone, Copy)]
+enum Mode<'a> {
+    Compute,
+    Verify(&'a [u8]),
+}
+
+/// Runs the HMAC of the type in the tag
+fn dispatch(tag: Tag, key: &Object, data: &[u8], mode: Mode<'_>) -> Result<Vec<u8>> {
+    let key_name = key.tag().canonical_name();
+    // only secret keys, the halves of key pairs are never HMAC keys
+    let symmetric = match key_name.as_str() {
+        "key.undefined" | "key.chacha20" => true,
+        name => name.starts_with("key.aes."),
+    };
+    if !symmetric {
+        return Err(Error::UnsupportedType(key_name));
+    }
+    let name = tag.canonical_name();
+    let key = key.data();
+    match name.as_str() {
+        "hmac.sha2.224" => run::<Hmac<Sha224>>(&name, key, data, mode),
+        "hmac.sha2.256" => run::<Hmac<Sha256>>(&name, key, data, mode),
+        "hmac.sha2.384" => run::<Hmac<Sha384>>(&name, key, data, mode),
+        "hmac.sha2.512" => run::<Hmac<Sha512>>(&name, key, data, mode),
+        "hmac.sha2.512/224" => run::<Hmac<Sha512_224>>(&name, key, data, mode),
+        "hmac.sha2.512/256" => run::<Hmac<Sha512_256>>(&name, key, data, mode),
+        "hmac.sha3.224" => run::<Hmac<Sha3_224>>(&name, key, data, mode),
+        "hmac.sha3.256" => run::<Hmac<Sha3_256>>(&name, key, data, mode),
+        "hmac.sha3.384" => run::<Hmac<Sha3_384>>(&name, key, data, mode),
+        "hmac.sha3.512" => run::<Hmac<Sha3_512>>(&name, key, data, mode),
+        // blake2 buffers its blocks lazily so it needs the simple hmac
+        "hmac.blake2.b" => run::<SimpleHmac<Blake2b512>>(&name, key, data, mode),
+        "hmac.blake2.s" => run::<SimpleHmac<Blake2s256>>(&name, key, data, mode),
+        _ => Err(Error::UnsupportedType(name)),
+    }
+}
+
+fn run<M: Mac + KeyInit>(name: &str, key: &[u8], data: &[u8], mode: Mode<'_>) -> Result<Vec<u8>> {
+    // hmac takes keys of any length
+    let mut mac = <M as KeyInit>::new_from_slice(key).map_err(|_| Error::InvalidKey {
+        name: name.to_string(),
+        reason: "wrong key size".to_string(),
+    })?;
+    mac.update(data);
+    match mode {
+        Mode::Compute => Ok(mac.finalize().into_bytes().to_vec()),
+        Mode::Verify(expected) => mac
+            .verify_slice(expected)
+            .map(|_| Vec::new())
+            .map_err(|_| Error::HmacMismatch(name.to_string())),
+    }
+}
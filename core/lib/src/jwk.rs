@@ -0,0 +1,255 @@
+//! Conversion between CDE keys and JSON Web Keys (RFC 7517, RFC 7518 and
+//! RFC 8037)
+//!
+//! The ed25519 and x25519 keys are "OKP" keys, the p256 and k256 keys are "EC"
+//! keys with the public key as the uncompressed x and y coordinates and the aes
+//! keys are "oct" keys. A JWK for a secret key always includes the public key
+//! so exporting one needs both halves of the key pair. In a `key.list` each
+//! secret key is followed by its public key.
+use crate::{
+    idx,
+    key::{self, Algorithm, Half},
+    Error, Object, Result, Tag, TagBuilder,
+};
+use data_encoding::BASE64URL_NOPAD;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Jwk {
+    kty: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    crv: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    d: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    k: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct JwkSet {
+    keys: Vec<Jwk>,
+}
+
+/// Exports a public key or an aes key as a JWK
+pub fn to_jwk(obj: &Object) -> Result<String> {
+    to_json(&jwk(obj, None)?)
+}
+
+/// Exports a secret key and its public key as a JWK
+pub fn secret_to_jwk(secret: &Object, public: &Object) -> Result<String> {
+    to_json(&jwk(public, Some(secret))?)
+}
+
+/// Imports the secret key from a JWK if it has one, otherwise the public or
+/// aes key
+pub fn from_jwk(s: &str) -> Result<Object> {
+    let (secret, key) = keys(&from_json(s)?)?;
+    Ok(secret.unwrap_or(key))
+}
+
+/// Imports the public key from a JWK, ignoring the secret key if there is one
+pub fn public_from_jwk(s: &str) -> Result<Object> {
+    let (_, key) = keys(&from_json(s)?)?;
+    if aes_len(&key.tag()).is_some() {
+        return Err(key::invalid(key.tag(), "not a public key"));
+    }
+    Ok(key)
+}
+
+/// Exports a `key.list` as a JWK Set
+pub fn to_jwk_set(list: &Object) -> Result<String> {
+    let mut set = JwkSet::default();
+    let mut items = list.items()?.into_iter();
+    while let Some(item) = items.next() {
+        let jwk = match key::algorithm(&item.tag()) {
+            Some((_, Half::Secret)) => {
+                let public = items
+                    .next()
+                    .ok_or_else(|| key::invalid(item.tag(), "not followed by its public key"))?;
+                jwk(&public, Some(&item))?
+            }
+            _ => jwk(&item, None)?,
+        };
+        set.keys.push(jwk);
+    }
+    to_json(&set)
+}
+
+/// A key in a JWK Set that was left out of the import
+#[derive(Debug)]
+pub struct Skipped {
+    /// the "kid" of the key, if it had one
+    pub kid: Option<String>,
+    /// why the key couldn't be imported
+    pub error: Error,
+}
+
+/// Imports a JWK Set as a `key.list`. Keys with an unsupported key type or
+/// curve, like RSA keys, are skipped and returned with their "kid" instead of
+/// failing the whole import, any other bad key is an error.
+pub fn from_jwk_set(s: &str) -> Result<(Object, Vec<Skipped>)> {
+    let set: JwkSet = from_json(s)?;
+    let mut items = Vec::with_capacity(set.keys.len());
+    let mut skipped = Vec::new();
+    for jwk in &set.keys {
+        match keys(jwk) {
+            Ok((secret, key)) => {
+                items.extend(secret);
+                items.push(key);
+            }
+            Err(error @ Error::UnsupportedAlgorithm(_)) => skipped.push(Skipped {
+                kid: jwk.kid.clone(),
+                error,
+            }),
+            Err(e) => return Err(e),
+        }
+    }
+    let list = Object::list(TagBuilder::from_tag("key.list").build()?, &items);
+    Ok((list, skipped))
+}
+
+/// Builds the JWK for a public or aes key and the optional secret key
+fn jwk(obj: &Object, secret: Option<&Object>) -> Result<Jwk> {
+    if let Some(len) = aes_len(&obj.tag()) {
+        if obj.data().len() != len {
+            return Err(key::invalid(obj.tag(), "wrong key size"));
+        }
+        if secret.is_some() {
+            return Err(key::invalid(obj.tag(), "not a public key"));
+        }
+        return Ok(Jwk {
+            kty: "oct".to_string(),
+            k: Some(BASE64URL_NOPAD.encode(obj.data())),
+            ..Jwk::default()
+        });
+    }
+
+    let (alg, half) = key::check(obj)?;
+    if half != Half::Public {
+        return Err(key::invalid(obj.tag(), "not a public key"));
+    }
+    let mut jwk = match alg {
+        Algorithm::Ed25519 | Algorithm::X25519 => Jwk {
+            kty: "OKP".to_string(),
+            x: Some(BASE64URL_NOPAD.encode(obj.data())),
+            ..Jwk::default()
+        },
+        Algorithm::P256 | Algorithm::K256 => {
+            let point = obj.data();
+            if point.len() != 65 {
+                return Err(key::invalid(obj.tag(), "point is not uncompressed"));
+            }
+            Jwk {
+                kty: "EC".to_string(),
+                x: Some(BASE64URL_NOPAD.encode(&point[1..33])),
+                y: Some(BASE64URL_NOPAD.encode(&point[33..])),
+                ..Jwk::default()
+            }
+        }
+        Algorithm::Rsa => return Err(Error::UnsupportedAlgorithm("rsa JWK".to_string())),
+    };
+    jwk.crv = curve(alg).map(str::to_string);
+
+    if let Some(secret) = secret {
+        if key::check(secret)? != (alg, Half::Secret) {
+            return Err(key::invalid(
+                secret.tag(),
+                "doesn't match the public key algorithm",
+            ));
+        }
+        jwk.d = Some(BASE64URL_NOPAD.encode(secret.data()));
+    }
+    Ok(jwk)
+}
+
+/// Returns the secret key if the JWK has one and the public or aes key
+fn keys(jwk: &Jwk) -> Result<(Option<Object>, Object)> {
+    let alg = match (jwk.kty.as_str(), jwk.crv.as_deref()) {
+        ("oct", _) => {
+            let k = field(&jwk.k, "k")?;
+            let tag = match k.len() {
+                16 => TagBuilder::from_tag("key.aes.128").build()?,
+                32 => TagBuilder::from_tag("key.aes.256").build()?,
+                n => return Err(Error::UnsupportedAlgorithm(format!("{} byte oct key", n))),
+            };
+            return Ok((None, Object::new(tag, &k)));
+        }
+        ("OKP", Some("Ed25519")) => Algorithm::Ed25519,
+        ("OKP", Some("X25519")) => Algorithm::X25519,
+        ("EC", Some("P-256")) => Algorithm::P256,
+        ("EC", Some("secp256k1")) => Algorithm::K256,
+        ("OKP", crv) | ("EC", crv) => {
+            return Err(Error::UnsupportedAlgorithm(format!(
+                "curve {}",
+                crv.unwrap_or("(none)")
+            )))
+        }
+        (kty, _) => return Err(Error::UnsupportedAlgorithm(format!("key type {}", kty))),
+    };
+
+    let mut public = field(&jwk.x, "x")?;
+    if alg == Algorithm::P256 || alg == Algorithm::K256 {
+        let y = field(&jwk.y, "y")?;
+        if public.len() != 32 || y.len() != 32 {
+            return Err(key::invalid(alg.tag(Half::Public), "wrong coordinate size"));
+        }
+        public.insert(0, 4);
+        public.extend_from_slice(&y);
+    }
+    let public = Object::new(alg.tag(Half::Public), &public);
+    key::check(&public)?;
+
+    let secret = if jwk.d.is_some() {
+        let secret = Object::new(alg.tag(Half::Secret), &field(&jwk.d, "d")?);
+        key::check(&secret)?;
+        Some(secret)
+    } else {
+        None
+    };
+    Ok((secret, public))
+}
+
+/// Returns the JWK curve name for the algorithm
+fn curve(alg: Algorithm) -> Option<&'static str> {
+    match alg {
+        Algorithm::Ed25519 => Some("Ed25519"),
+        Algorithm::X25519 => Some("X25519"),
+        Algorithm::P256 => Some("P-256"),
+        Algorithm::K256 => Some("secp256k1"),
+        Algorithm::Rsa => None,
+    }
+}
+
+/// Returns the key size for aes key tags
+fn aes_len(tag: &Tag) -> Option<usize> {
+    if tag.class() != idx('k') || tag.subclass() != idx('a') {
+        return None;
+    }
+    match tag.subsubclass() {
+        0 => Some(16),
+        1 => Some(32),
+        _ => None,
+    }
+}
+
+/// Decodes a required base64url member of the JWK
+fn field(value: &Option<String>, name: &str) -> Result<Vec<u8>> {
+    let value = value
+        .as_ref()
+        .ok_or_else(|| Error::JwkError(format!("missing '{}'", name)))?;
+    Ok(BASE64URL_NOPAD.decode(value.as_bytes())?)
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String> {
+    serde_json::to_string(value).map_err(|e| Error::JwkError(e.to_string()))
+}
+
+fn from_json<'a, T: Deserialize<'a>>(s: &'a str) -> Result<T> {
+    serde_json::from_str(s).map_err(|e| Error::JwkError(e.to_string()))
+}
@@ -0,0 +1,285 @@
+//! Deriving keys with the parameters in `Kdf.list` objects
+//!
+//! The key derivation functions are the experimental `Kdf` class until the
+//! spec standardises them. A parameter record is a `Kdf.list` object holding
+//! the `Kdf.*` object of the algorithm with its cost parameters as VarUInts,
+//! then the salt as a `nonce.bytes` object and for hkdf and pbkdf2 an empty
+//! `hmac.*` object naming the hash. The hkdf info follows as an
+//! `undefined.undefined` object. The cost parameters are:
+//!
+//! * hkdf: none
+//! * pbkdf2: the iterations
+//! * scrypt: log2 of the cost, the block size and the parallelism
+//! * argon2: the variant (0 for argon2d, 1 for argon2i, 2 for argon2id), the
+//!   memory in KiB, the iterations and the parallelism
+//!
+//! The hashes are sha2-256, sha2-384 and sha2-512 and the derived keys are the
+//! symmetric keys and the ed25519 and x25519 secret keys.
+use crate::{CryptoData, Error, Object, Result, Tag, TagBuilder, VarUInt};
+use core::convert::TryFrom;
+use hkdf::Hkdf;
+use sha2::{Sha256, Sha384, Sha512};
+
+/// The key types that can be derived and their sizes
+static KEYS: [(&str, usize); 5] = [
+    ("key.aes.128", 16),
+    ("key.aes.256", 32),
+    ("key.chacha20", 32),
+    ("key.ed25519.secret", 32),
+    ("key.x25519.secret", 32),
+];
+
+/// The argon2 variants in the order of their parameter values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Argon2Variant {
+    Argon2d,
+    Argon2i,
+    Argon2id,
+}
+
+/// The parameters of a key derivation
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Params {
+    Hkdf {
+        /// the `hmac.*` type of the hash
+        hash: Tag,
+        salt: Vec<u8>,
+        info: Vec<u8>,
+    },
+    Pbkdf2 {
+        /// the `hmac.*` type of the hash
+        hash: Tag,
+        salt: Vec<u8>,
+        iterations: u32,
+    },
+    Scrypt {
+        salt: Vec<u8>,
+        log_n: u8,
+        r: u32,
+        p: u32,
+    },
+    Argon2 {
+        variant: Argon2Variant,
+        salt: Vec<u8>,
+        /// the memory in KiB
+        memory: u32,
+        iterations: u32,
+        parallelism: u32,
+    },
+}
+
+impl Params {
+    /// Reads the parameters from a `Kdf.list` object
+    pub fn from_object(obj: &Object) -> Result<Self> {
+        if obj.tag().canonical_name() != "Kdf.list" {
+            return Err(Error::UnsupportedType(obj.tag().canonical_name()));
+        }
+        let items = obj.items()?;
+        let (alg, rest) = items
+            .split_first()
+            .ok_or_else(|| kdf_err("missing the algorithm"))?;
+        let name = alg.tag().canonical_name();
+        let costs = varuints(alg.data())?;
+        let salt = match rest.first() {
+            Some(salt) if salt.tag().canonical_name() == "nonce.bytes" => salt.data().to_vec(),
+            _ => return Err(kdf_err("missing the nonce.bytes salt")),
+        };
+        let params = match (name.as_str(), costs.as_slice(), &rest[1..]) {
+            ("Kdf.hkdf", [], [hash, info]) => {
+                if info.tag().canonical_name() != "undefined.undefined" {
+                    return Err(kdf_err("the info isn't an undefined.undefined object"));
+                }
+                Params::Hkdf {
+                    hash: hash_tag(hash)?,
+                    salt,
+                    info: info.data().to_vec(),
+                }
+            }
+            ("Kdf.pbkdf2", [iterations], [hash]) => Params::Pbkdf2 {
+                hash: hash_tag(hash)?,
+                salt,
+                iterations: cost(*iterations)?,
+            },
+            ("Kdf.scrypt", [log_n, r, p], []) => Params::Scrypt {
+                salt,
+                log_n: u8::try_from(*log_n).map_err(|_| kdf_err("cost out of range"))?,
+                r: cost(*r)?,
+                p: cost(*p)?,
+            },
+            ("Kdf.argon2", [variant, memory, iterations, parallelism], []) => Params::Argon2 {
+                variant: match variant {
+                    0 => Argon2Variant::Argon2d,
+                    1 => Argon2Variant::Argon2i,
+                    2 => Argon2Variant::Argon2id,
+                    _ => return Err(kdf_err("unknown argon2 variant")),
+                },
+                salt,
+                memory: cost(*memory)?,
+                iterations: cost(*iterations)?,
+                parallelism: cost(*parallelism)?,
+            },
+            ("Kdf.hkdf", _, _)
+            | ("Kdf.pbkdf2", _, _)
+            | ("Kdf.scrypt", _, _)
+            | ("Kdf.argon2", _, _) => {
+                return Err(kdf_err(format!("wrong parameters for {}", name)))
+            }
+            _ => return Err(Error::UnsupportedType(name)),
+        };
+        Ok(params)
+    }
+
+    /// Returns the parameters as a `Kdf.list` object
+    pub fn to_object(&self) -> Result<Object> {
+        let (name, costs, salt): (_, Vec<u64>, _) = match self {
+            Params::Hkdf { salt, .. } => ("Kdf.hkdf", vec![], salt),
+            Params::Pbkdf2 {
+                salt, iterations, ..
+            } => ("Kdf.pbkdf2", vec![(*iterations).into()], salt),
+            Params::Scrypt { salt, log_n, r, p } => (
+                "Kdf.scrypt",
+                vec![(*log_n).into(), (*r).into(), (*p).into()],
+                salt,
+            ),
+            Params::Argon2 {
+                variant,
+                salt,
+                memory,
+                iterations,
+                parallelism,
+            } => (
+                "Kdf.argon2",
+                vec![
+                    *variant as u64,
+                    (*memory).into(),
+                    (*iterations).into(),
+                    (*parallelism).into(),
+                ],
+                salt,
+            ),
+        };
+        let mut data = Vec::new();
+        for c in costs {
+            let v = VarUInt::from(c);
+            let mut b = vec![0; v.len()];
+            v.bytes(&mut b);
+            data.extend_from_slice(&b);
+        }
+        let mut items = vec![
+            Object::from_type(name, &data)?,
+            Object::from_type("nonce.bytes", salt)?,
+        ];
+        match self {
+            Params::Hkdf { hash, info, .. } => {
+                items.push(Object::new(*hash, &[]));
+                items.push(Object::from_type("undefined.undefined", info)?);
+            }
+            Params::Pbkdf2 { hash, .. } => items.push(Object::new(*hash, &[])),
+            _ => {}
+        }
+        Ok(Object::list(
+            TagBuilder::from_tag("Kdf.list").build()?,
+            &items,
+        ))
+    }
+}
+
+/// Runs the body with the type `$h` set to the hash named by an `hmac.*` tag
+macro_rules! with_hash {
+    ($kdf:expr, $hash:expr, $h:ident => $body:expr) => {
+        match $hash.canonical_name().as_str() {
+            "hmac.sha2.256" => {
+                type $h = Sha256;
+                $body
+            }
+            "hmac.sha2.384" => {
+                type $h = Sha384;
+                $body
+            }
+            "hmac.sha2.512" => {
+                type $h = Sha512;
+                $body
+            }
+            name => {
+                return Err(Error::UnsupportedAlgorithm(format!(
+                    "{} with {}",
+                    $kdf, name
+                )))
+            }
+        }
+    };
+}
+
+/// Derives a key of the type in the tag from the secret, a password or other
+/// input keying material
+pub fn derive(params: &Params, secret: &[u8], tag: Tag) -> Result<Object> {
+    let name = tag.canonical_name();
+    let len = match KEYS.iter().find(|(n, _)| *n == name) {
+        Some((_, len)) => *len,
+        None => return Err(Error::UnsupportedType(name)),
+    };
+    let mut key = vec![0; len];
+    match params {
+        Params::Hkdf { hash, salt, info } => with_hash!("hkdf", hash, H => {
+            Hkdf::<H>::new(Some(salt), secret)
+                .expand(info, &mut key)
+                .map_err(kdf_err)?
+        }),
+        Params::Pbkdf2 {
+            hash,
+            salt,
+            iterations,
+        } => with_hash!("pbkdf2", hash, H => {
+            pbkdf2::pbkdf2_hmac::<H>(secret, salt, *iterations, &mut key)
+        }),
+        Params::Scrypt { salt, log_n, r, p } => {
+            let params = scrypt::Params::new(*log_n, *r, *p, len).map_err(kdf_err)?;
+            scrypt::scrypt(secret, salt, &params, &mut key).map_err(kdf_err)?
+        }
+        Params::Argon2 {
+            variant,
+            salt,
+            memory,
+            iterations,
+            parallelism,
+        } => {
+            let alg = match variant {
+                Argon2Variant::Argon2d => argon2::Algorithm::Argon2d,
+                Argon2Variant::Argon2i => argon2::Algorithm::Argon2i,
+                Argon2Variant::Argon2id => argon2::Algorithm::Argon2id,
+            };
+            let params = argon2::Params::new(*memory, *iterations, *parallelism, Some(len))
+                .map_err(kdf_err)?;
+            argon2::Argon2::new(alg, argon2::Version::V0x13, params)
+                .hash_password_into(secret, salt, &mut key)
+                .map_err(kdf_err)?
+        }
+    }
+    Ok(Object::new(tag, &key))
+}
+
+/// Reads the padded VarUInts of the cost parameters
+fn varuints(mut b: &[u8]) -> Result<Vec<u64>> {
+    let mut v = Vec::new();
+    while !b.is_empty() {
+        let n = VarUInt::try_parse(b)?;
+        b = b.get(n.len()..).ok_or(Error::InvalidLength)?;
+        v.push(n.into());
+    }
+    Ok(v)
+}
+
+fn cost(v: u64) -> Result<u32> {
+    u32::try_from(v).map_err(|_| kdf_err("cost out of range"))
+}
+
+fn hash_tag(obj: &Object) -> Result<Tag> {
+    if !obj.tag().canonical_name().starts_with("hmac.") {
+        return Err(kdf_err("the hash isn't an hmac.* object"));
+    }
+    Ok(obj.tag())
+}
+
+fn kdf_err<E: core::fmt::Display>(e: E) -> Error {
+    Error::KdfError(e.to_string())
+}
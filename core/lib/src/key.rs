@@ -0,0 +1,140 @@
+//! Helpers for the asymmetric key types shared by the conversion modules
+use crate::{idx, Error, Object, Result, Tag};
+
+/// The asymmetric key algorithms in the registry
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Algorithm {
+    Ed25519,
+    X25519,
+    P256,
+    K256,
+    Rsa,
+}
+
+/// Which half of a key pair a key is
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Half {
+    Public,
+    Secret,
+}
+
+impl Algorithm {
+    fn subclass(self) -> char {
+        match self {
+            Algorithm::Ed25519 => 'e',
+            Algorithm::X25519 => 'x',
+            Algorithm::P256 => 'p',
+            Algorithm::K256 => 'k',
+            Algorithm::Rsa => 'r',
+        }
+    }
+
+    /// Returns the tag for a key of this algorithm
+    pub(crate) fn tag(self, half: Half) -> Tag {
+        match half {
+            Half::Public => self.tag_with(0),
+            Half::Secret => self.tag_with(1),
+        }
+    }
+
+    /// Returns the verifying or signing key tag for p256 and k256 keys
+    #[cfg(any(feature = "k256", feature = "p256"))]
+    pub(crate) fn ecdsa_tag(self, half: Half) -> Tag {
+        match half {
+            Half::Public => self.tag_with(2),
+            Half::Secret => self.tag_with(3),
+        }
+    }
+
+    fn tag_with(self, ssc: u8) -> Tag {
+        Tag::default()
+            .with_class(idx('k'))
+            .and_then(|t| t.with_subclass(idx(self.subclass())))
+            .and_then(|t| t.with_subsubclass(ssc))
+            .unwrap_or_default()
+    }
+}
+
+/// Returns the algorithm and half for a key tag. The p256 and k256 verifying
+/// and signing keys are the same as the public and secret keys.
+pub(crate) fn algorithm(tag: &Tag) -> Option<(Algorithm, Half)> {
+    if tag.class() != idx('k') {
+        return None;
+    }
+    let alg = [
+        Algorithm::Ed25519,
+        Algorithm::X25519,
+        Algorithm::P256,
+        Algorithm::K256,
+        Algorithm::Rsa,
+    ]
+    .iter()
+    .copied()
+    .find(|a| tag.subclass() == idx(a.subclass()))?;
+    let half = match (alg, tag.subsubclass()) {
+        (_, 0) => Half::Public,
+        (_, 1) => Half::Secret,
+        (Algorithm::P256, 2) | (Algorithm::K256, 2) => Half::Public,
+        (Algorithm::P256, 3) | (Algorithm::K256, 3) => Half::Secret,
+        _ => return None,
+    };
+    Some((alg, half))
+}
+
+/// Checks that the object is a key with a valid size for its algorithm
+pub(crate) fn check(obj: &Object) -> Result<(Algorithm, Half)> {
+    let (alg, half) =
+        algorithm(&obj.tag()).ok_or_else(|| Error::UnsupportedType(obj.tag().canonical_name()))?;
+    let key = obj.data();
+    let valid = match (alg, half) {
+        (Algorithm::Ed25519, _) | (Algorithm::X25519, _) => key.len() == 32,
+        (Algorithm::P256, Half::Secret) | (Algorithm::K256, Half::Secret) => key.len() == 32,
+        (Algorithm::P256, Half::Public) | (Algorithm::K256, Half::Public) => match key.first() {
+            Some(2) | Some(3) => key.len() == 33,
+            Some(4) => key.len() == 65,
+            _ => false,
+        },
+        (Algorithm::Rsa, _) => !key.is_empty(),
+    };
+    if !valid {
+        return Err(invalid(obj.tag(), "wrong key size"));
+    }
+    Ok((alg, half))
+}
+
+pub(crate) fn invalid(tag: Tag, reason: &str) -> Error {
+    Error::InvalidKey {
+        name: tag.canonical_name(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Checks that the object is a valid key of the algorithm and half
+#[cfg(any(
+    feature = "ed25519",
+    feature = "k256",
+    feature = "p256",
+    feature = "rsa",
+    feature = "x25519"
+))]
+pub(crate) fn expect(obj: &Object, alg: Algorithm, half: Half) -> Result<()> {
+    if algorithm(&obj.tag()).map(|(a, _)| a) != Some(alg) {
+        return Err(Error::UnsupportedType(obj.tag().canonical_name()));
+    }
+    match check(obj)? {
+        (_, Half::Public) if half == Half::Secret => Err(invalid(obj.tag(), "not a secret key")),
+        (_, Half::Secret) if half == Half::Public => Err(invalid(obj.tag(), "not a public key")),
+        _ => Ok(()),
+    }
+}
+
+/// Returns the bytes of a 32 byte key
+#[cfg(any(feature = "ed25519", feature = "x25519"))]
+pub(crate) fn bytes(obj: &Object) -> Result<[u8; 32]> {
+    let mut b = [0u8; 32];
+    if obj.data().len() != b.len() {
+        return Err(invalid(obj.tag(), "wrong key size"));
+    }
+    b.copy_from_slice(obj.data());
+    Ok(b)
+}
@@ -0,0 +1,100 @@
+//! Key generation
+//!
+//! The symmetric keys are random bytes from the operating system and the
+//! asymmetric keys are made with the backend enabled by the feature of the
+//! same name, generating a key type without its backend gives an
+//! `UnsupportedAlgorithm` error.
+use crate::{Error, Object, Result, Tag};
+#[cfg(any(
+    feature = "ed25519",
+    feature = "k256",
+    feature = "p256",
+    feature = "rsa",
+    feature = "x25519"
+))]
+use core::convert::TryFrom;
+use rand_core::{OsRng, RngCore};
+
+/// The asymmetric key types and the features that generate them
+static BACKENDS: [(&str, &str); 6] = [
+    ("key.ed25519.secret", "ed25519"),
+    ("key.x25519.secret", "x25519"),
+    ("key.p256.secret", "p256"),
+    ("key.p256.signing", "p256"),
+    ("key.k256.secret", "k256"),
+    ("key.k256.signing", "k256"),
+];
+
+/// Generates a new secret key of the type in the tag
+pub fn generate(tag: Tag) -> Result<Object> {
+    let name = tag.canonical_name();
+    match name.as_str() {
+        "key.aes.128" => Ok(Object::new(tag, &random::<16>())),
+        "key.aes.256" | "key.chacha20" => Ok(Object::new(tag, &random::<32>())),
+        #[cfg(feature = "ed25519")]
+        "key.ed25519.secret" => Ok(Object::from(&ed25519_dalek::SigningKey::from_bytes(
+            &random(),
+        ))),
+        #[cfg(feature = "x25519")]
+        "key.x25519.secret" => Ok(Object::from(&x25519_dalek::StaticSecret::random_from_rng(
+            OsRng,
+        ))),
+        #[cfg(feature = "p256")]
+        "key.p256.secret" => Ok(Object::from(&p256::SecretKey::random(&mut OsRng))),
+        #[cfg(feature = "p256")]
+        "key.p256.signing" => Ok(Object::from(&p256::ecdsa::SigningKey::random(&mut OsRng))),
+        #[cfg(feature = "k256")]
+        "key.k256.secret" => Ok(Object::from(&k256::SecretKey::random(&mut OsRng))),
+        #[cfg(feature = "k256")]
+        "key.k256.signing" => Ok(Object::from(&k256::ecdsa::SigningKey::random(&mut OsRng))),
+        _ => match BACKENDS.iter().find(|(n, _)| *n == name) {
+            Some((_, feature)) => Err(Error::UnsupportedAlgorithm(format!(
+                "{} needs the '{}' feature",
+                name, feature
+            ))),
+            None => Err(Error::UnsupportedType(name)),
+        },
+    }
+}
+
+/// Derives the public key for a secret key, the verifying key for a signing
+/// key
+pub fn public_key(secret: &Object) -> Result<Object> {
+    match secret.tag().canonical_name().as_str() {
+        #[cfg(feature = "ed25519")]
+        "key.ed25519.secret" => Ok(Object::from(
+            &ed25519_dalek::SigningKey::try_from(secret)?.verifying_key(),
+        )),
+        #[cfg(feature = "x25519")]
+        "key.x25519.secret" => Ok(Object::from(&x25519_dalek::PublicKey::from(
+            &x25519_dalek::StaticSecret::try_from(secret)?,
+        ))),
+        #[cfg(feature = "p256")]
+        "key.p256.secret" => Ok(Object::from(
+            &p256::SecretKey::try_from(secret)?.public_key(),
+        )),
+        #[cfg(feature = "p256")]
+        "key.p256.signing" => Ok(Object::from(
+            p256::ecdsa::SigningKey::try_from(secret)?.verifying_key(),
+        )),
+        #[cfg(feature = "k256")]
+        "key.k256.secret" => Ok(Object::from(
+            &k256::SecretKey::try_from(secret)?.public_key(),
+        )),
+        #[cfg(feature = "k256")]
+        "key.k256.signing" => Ok(Object::from(
+            k256::ecdsa::SigningKey::try_from(secret)?.verifying_key(),
+        )),
+        #[cfg(feature = "rsa")]
+        "key.rsa.secret" => {
+            Object::try_from(&rsa::RsaPrivateKey::try_from(secret)?.to_public_key())
+        }
+        name => Err(Error::UnsupportedType(name.to_string())),
+    }
+}
+
+fn random<const N: usize>() -> [u8; N] {
+    let mut b = [0u8; N];
+    OsRng.fill_bytes(&mut b);
+    b
+}
@@ -1,5 +1,6 @@
-use anyhow;
-use data_encoding::Encoding;
+use core::fmt;
+pub use data_encoding::DecodeKind;
+use data_encoding::{DecodeError, Encoding};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -10,36 +11,136 @@ pub enum Error {
     IoError(#[from] std::io::Error),
     #[error("fmt error")]
     FmtError(#[from] std::fmt::Error),
-    #[error("invalid class")]
-    InvalidClass,
-    #[error("invalid sub-class")]
-    InvalidSubClass,
-    #[error("invalid sub-sub-class value")]
-    InvalidSubSubClass,
-    #[error("numerical type value out of range (>=64)")]
-    InvalidTypeNumber(u8),
-    #[error("type name begins with invalid letter not in [a-zA-Z0-9-_]")]
-    InvalidTypeFirstLetter,
-    #[error("parent type is non-experimental")]
-    NonExperimentalParentType(u8),
-    #[error("invalid non-ascii type name")]
-    InvalidTypeName,
-    #[error("failed to build from str")]
-    FromStr,
+    #[error("invalid class '{segment}': {reason}")]
+    InvalidClass { segment: String, reason: Reason },
+    #[error("invalid sub-class '{segment}': {reason}")]
+    InvalidSubClass { segment: String, reason: Reason },
+    #[error("invalid sub-sub-class '{segment}': {reason}")]
+    InvalidSubSubClass { segment: String, reason: Reason },
+    #[error("invalid type name '{name}': {reason}")]
+    InvalidTypeName { name: String, reason: Reason },
     #[error("failed to build from bytes")]
     FromBytes,
     #[error("no buffer given")]
     MissingBuf,
-    #[error("decode error")]
-    DecodeError,
+    #[error("decode error at byte offset {offset}: {kind}")]
+    DecodeError { offset: usize, kind: DecodeKind },
     #[error("invalid length")]
     InvalidLength,
+    #[error("lists nested more than {0} deep")]
+    ListTooDeep(usize),
+    #[error("minisign error: {0}")]
+    MinisignError(String),
+    #[error("no multicodec code for type '{0}'")]
+    NoMulticodec(String),
+    #[error("unknown multicodec code 0x{0:x}")]
+    UnknownMulticodec(u64),
+    #[error("input is not a recognised CDE encoding")]
+    UnknownFormat,
+    #[error("unsupported type '{0}'")]
+    UnsupportedType(String),
+    #[error("unsupported algorithm: {0}")]
+    UnsupportedAlgorithm(String),
+    #[error("invalid {name} key: {reason}")]
+    InvalidKey { name: String, reason: String },
+    #[error("COSE error: {0}")]
+    CoseError(String),
+    #[error("DER error: {0}")]
+    DerError(String),
+    #[error("invalid DID: {0}")]
+    InvalidDid(String),
+    #[error("JWK error: {0}")]
+    JwkError(String),
+    #[error("OpenSSH key error: {0}")]
+    SshError(String),
+    #[error("invalid PEM: {0}")]
+    InvalidPem(String),
+    #[error("PEM label '{label}' doesn't match the type '{name}'")]
+    PemLabelMismatch { label: String, name: String },
+    #[error("invalid OpenPGP signature: {0}")]
+    InvalidPgp(String),
+    #[error("invalid X.509 certificate: {0}")]
+    InvalidCertificate(String),
+    #[error("invalid signature: {0}")]
+    InvalidSignature(String),
+    #[error("bad signature")]
+    BadSignature,
+    #[error("{0} digest doesn't match")]
+    DigestMismatch(String),
+    #[error("{0} HMAC doesn't match")]
+    HmacMismatch(String),
+    #[error("KDF error: {0}")]
+    KdfError(String),
+    #[error("AEAD error: {0}")]
+    AeadError(String),
+    #[error("decryption failed")]
+    DecryptionFailed,
+    #[error("no multihash code for type '{0}'")]
+    NoMultihash(String),
+    #[error("unknown multihash code 0x{0:x}")]
+    UnknownMultihash(u64),
+    #[error("invalid {name} digest length {actual}, expected {expected}")]
+    InvalidDigestLength {
+        name: String,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+impl From<DecodeError> for Error {
+    fn from(e: DecodeError) -> Self {
+        Error::DecodeError {
+            offset: e.position,
+            kind: e.kind,
+        }
+    }
+}
+
+/// The reason a part of a type string was rejected
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Reason {
+    /// the part is required but wasn't given
+    Missing,
+    /// the name begins with a letter not in the CDE alphabet
+    InvalidFirstLetter,
+    /// a non-standard name maps to a non-experimental value
+    NotExperimental,
+    /// a non-standard sub-class was given for a non-experimental class
+    ParentNotExperimental,
+    /// a non-experimental sub-class was given for an experimental class
+    ParentExperimental,
+    /// the sub-sub-class is neither a standard name nor a base 10 number
+    NotANumber,
+    /// the numerical value doesn't fit in the bits for its part
+    OutOfRange,
+    /// the sub-class doesn't allow a non-standard sub-sub-class
+    NotAllowed,
+    /// there are more than three '.' separated parts
+    TooManyParts,
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Reason::Missing => "missing",
+            Reason::InvalidFirstLetter => "begins with a letter not in [a-zA-Z0-9-_]",
+            Reason::NotExperimental => "non-standard names must be experimental",
+            Reason::ParentNotExperimental => "non-standard names require an experimental class",
+            Reason::ParentExperimental => "experimental classes require an experimental sub-class",
+            Reason::NotANumber => "not a standard name or a base 10 number",
+            Reason::OutOfRange => "numerical value out of range",
+            Reason::NotAllowed => {
+                "only experimental sub-classes may have a non-standard sub-sub-class"
+            }
+            Reason::TooManyParts => "too many '.' separated parts",
+        };
+        f.write_str(s)
+    }
 }
 
 pub type Result<T> = anyhow::Result<T, Error>;
 
-pub static CDE_ALPHABET: &'static str =
-    "abcdefghijklmnopqrstuvwxyz01234-ABCDEFGHIJKLMNOPQRSTUVWXYZ56789_";
+pub static CDE_ALPHABET: &str = "abcdefghijklmnopqrstuvwxyz01234-ABCDEFGHIJKLMNOPQRSTUVWXYZ56789_";
 pub static ENCODER: Encoding = data_encoding_macro::new_encoding! {
     symbols: "abcdefghijklmnopqrstuvwxyz01234-ABCDEFGHIJKLMNOPQRSTUVWXYZ56789_",
 };
@@ -64,24 +165,18 @@ pub fn decode_tag_and_data<'a, T: From<&'a [u8]>>(
     encoded: &[u8],
     buf: &'a mut [u8],
 ) -> Result<(Tag, T)> {
-    let len = ENCODER
-        .decode_len(encoded.len())
-        .map_err(|_| Error::DecodeError)?;
+    let len = ENCODER.decode_len(encoded.len())?;
     ENCODER
         .decode_mut(encoded, &mut buf[0..len])
-        .map_err(|_| Error::DecodeError)?;
-    let tag = TagBuilder::from_bytes(&buf).build()?;
+        .map_err(|p| p.error)?;
+    let tag = TagBuilder::from_bytes(buf).build()?;
     let len = tag.len();
     let data_len = tag.get_data_length();
     let data = T::from(&buf[len..len + data_len]);
     Ok((tag, data))
 }
 
-pub fn encode_tag_and_data<'a>(
-    tag: &mut Tag,
-    data: &impl CryptoData,
-    buf: &'a mut [u8],
-) -> Result<usize> {
+pub fn encode_tag_and_data(tag: &mut Tag, data: &impl CryptoData, buf: &mut [u8]) -> Result<usize> {
     tag.set_data_length(data.len());
     let tagsize = tag.encode(buf);
     let datasize = data.encode(&mut buf[tagsize..]);
@@ -90,12 +185,75 @@ pub fn encode_tag_and_data<'a>(
 
 pub trait CryptoData {
     fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     fn bytes(&self, buf: &mut [u8]) -> usize;
     fn encode_len(&self) -> usize;
     fn encode(&self, buf: &mut [u8]) -> usize;
 }
 
+#[cfg(any(feature = "aes-gcm", feature = "chacha20poly1305"))]
+pub mod aead;
+#[cfg(feature = "cose")]
+pub mod cose;
+#[cfg(feature = "der")]
+pub mod der;
+#[cfg(feature = "did")]
+pub mod did;
+#[cfg(feature = "digest")]
+pub mod digest;
+#[cfg(any(feature = "k256", feature = "p256"))]
+mod ec;
+#[cfg(feature = "ed25519")]
+mod ed25519;
+#[cfg(feature = "hmac")]
+pub mod hmac;
+#[cfg(feature = "jwk")]
+pub mod jwk;
+#[cfg(feature = "kdf")]
+pub mod kdf;
+#[cfg(any(
+    feature = "cose",
+    feature = "der",
+    feature = "ed25519",
+    feature = "jwk",
+    feature = "k256",
+    feature = "openssh",
+    feature = "p256",
+    feature = "rsa",
+    feature = "x25519"
+))]
+mod key;
+#[cfg(feature = "keygen")]
+pub mod keygen;
+#[cfg(feature = "lock")]
+pub mod locked;
+#[cfg(feature = "minisign")]
+pub mod minisign;
+pub mod multibase;
+pub mod multicodec;
+pub mod multihash;
+mod object;
+pub use object::*;
+#[cfg(feature = "openssh")]
+pub mod openssh;
+pub mod pem;
+pub mod pgp;
+#[cfg(feature = "rsa")]
+mod rsa;
+#[cfg(any(
+    feature = "ed25519",
+    feature = "k256",
+    feature = "p256",
+    feature = "rsa"
+))]
+pub mod signature;
 mod tag;
 pub use tag::*;
 mod varuint;
 pub use varuint::*;
+#[cfg(feature = "x25519")]
+mod x25519;
+#[cfg(feature = "x509")]
+pub mod x509;
@@ -0,0 +1,119 @@
+//! Password locked secret keys as `key.Locked` objects
+//!
+//! A locked key is stored on disk without the plaintext secret. The data of a
+//! `key.Locked` object is the `Kdf.list` parameters followed by the `aead.list`
+//! envelope of the secret key object. The password and parameters derive a
+//! `key.chacha20` key that seals the secret with xchacha20-poly1305-ietf. The
+//! class is experimental until the spec standardises it. Locking uses argon2id
+//! with 19 MiB of memory, two iterations and a random 16 byte salt unless
+//! other parameters are given. Any of argon2, scrypt or pbkdf2 may lock a key
+//! but hkdf doesn't stretch the password so it can't, and parameters asking
+//! for more than 1 GiB of memory are refused. Only xchacha20-poly1305-ietf
+//! envelopes unlock.
+use crate::{
+    aead::{self, Envelope},
+    kdf::{self, Argon2Variant, Params},
+    Error, Object, Result, TagBuilder,
+};
+use chacha20poly1305::aead::{rand_core::RngCore, OsRng};
+
+/// Locks the secret key with the password using the default parameters
+pub fn lock(secret: &Object, password: &[u8]) -> Result<Object> {
+    let mut salt = vec![0; 16];
+    OsRng.fill_bytes(&mut salt);
+    let params = Params::Argon2 {
+        variant: Argon2Variant::Argon2id,
+        salt,
+        memory: 19456,
+        iterations: 2,
+        parallelism: 1,
+    };
+    lock_with(secret, password, &params)
+}
+
+/// Locks the secret key with the password using the key derivation parameters
+pub fn lock_with(secret: &Object, password: &[u8], params: &Params) -> Result<Object> {
+    let name = secret.tag().canonical_name();
+    if !name.starts_with("key.") || secret.is_list() || name == "key.Locked" {
+        return Err(Error::UnsupportedType(name));
+    }
+    if name.ends_with(".public") || name.ends_with(".verifying") {
+        return Err(Error::InvalidKey {
+            name,
+            reason: "not a secret key".to_string(),
+        });
+    }
+    check_params(params)?;
+    let key = kdf::derive(
+        params,
+        password,
+        TagBuilder::from_tag("key.chacha20").build()?,
+    )?;
+    let alg = TagBuilder::from_tag("aead.xchacha20-poly1305-ietf").build()?;
+    let envelope = aead::seal(&key, alg, &secret.to_bytes(), &[])?;
+    let mut data = params.to_object()?.to_bytes();
+    data.extend_from_slice(&envelope.to_object()?.to_bytes());
+    Object::from_type("key.Locked", &data)
+}
+
+/// Unlocks the secret key with the password, a wrong password gives a
+/// `DecryptionFailed` error
+pub fn unlock(locked: &Object, password: &[u8]) -> Result<Object> {
+    if locked.tag().canonical_name() != "key.Locked" {
+        return Err(Error::UnsupportedType(locked.tag().canonical_name()));
+    }
+    let (params, len) = Object::parse(locked.data())?;
+    let envelope = Envelope::from_object(&Object::from_bytes(&locked.data()[len..])?)?;
+    let params = Params::from_object(&params)?;
+    check_params(&params)?;
+    let alg = envelope.algorithm().canonical_name();
+    if alg != "aead.xchacha20-poly1305-ietf" {
+        return Err(Error::UnsupportedAlgorithm(alg));
+    }
+    let key = kdf::derive(
+        &params,
+        password,
+        TagBuilder::from_tag("key.chacha20").build()?,
+    )?;
+    Object::from_bytes(&aead::open(&key, &envelope)?)
+}
+
+/// The most memory in bytes a locked key may ask of argon2 or scrypt
+const MAX_MEMORY: u64 = 1 << 30;
+/// The most passes over the memory or lanes a locked key may ask for
+const MAX_PASSES: u32 = 16;
+/// The most pbkdf2 iterations a locked key may ask for
+const MAX_ITERATIONS: u32 = 10_000_000;
+
+/// Checks that the parameters stretch the password and don't ask for more
+/// memory or time than any sane lock would, so a crafted key can't exhaust
+/// the host
+fn check_params(params: &Params) -> Result<()> {
+    let ok = match *params {
+        Params::Hkdf { .. } => {
+            return Err(Error::UnsupportedAlgorithm(
+                "Kdf.hkdf doesn't stretch passwords".to_string(),
+            ))
+        }
+        Params::Pbkdf2 { iterations, .. } => iterations <= MAX_ITERATIONS,
+        Params::Scrypt { log_n, r, p, .. } => {
+            log_n <= 30 && 128 * u64::from(r) <= MAX_MEMORY >> log_n && p <= MAX_PASSES
+        }
+        Params::Argon2 {
+            memory,
+            iterations,
+            parallelism,
+            ..
+        } => {
+            u64::from(memory) * 1024 <= MAX_MEMORY
+                && iterations <= MAX_PASSES
+                && parallelism <= MAX_PASSES
+        }
+    };
+    match ok {
+        true => Ok(()),
+        false => Err(Error::KdfError(
+            "costs too high for a locked key".to_string(),
+        )),
+    }
+}
@@ -0,0 +1,302 @@
+//! Minisign public key, unencrypted secret key and signature files
+//!
+//! The keys in the files are `key.ed25519.public` and `key.ed25519.secret`
+//! objects and the key id and comments are kept alongside them so that the
+//! files are written back byte for byte. A signature is kept as a
+//! `signature.minisign` object holding the text of the `.minisig` file.
+use crate::{Error, Object, Result};
+use blake2::{digest::consts::U32, Blake2b, Digest};
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+use data_encoding::BASE64;
+
+const UNTRUSTED: &str = "untrusted comment: ";
+const TRUSTED: &str = "trusted comment: ";
+
+/// The signature algorithms, the second signs the BLAKE2b-512 hash of the file
+const ED25519: &[u8] = b"Ed";
+const ED25519_PREHASHED: &[u8] = b"ED";
+
+const KDF_NONE: &[u8] = b"\0\0";
+const KDF_SCRYPT: &[u8] = b"Sc";
+const CHECKSUM_BLAKE2B: &[u8] = b"B2";
+
+/// A minisign public key file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    /// the untrusted comment
+    pub comment: String,
+    /// the key id, as stored in the file
+    pub key_id: [u8; 8],
+    /// the `key.ed25519.public` key
+    pub key: Object,
+}
+
+/// An unencrypted minisign secret key file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecretKey {
+    /// the untrusted comment
+    pub comment: String,
+    /// the key id, as stored in the file
+    pub key_id: [u8; 8],
+    /// the `key.ed25519.secret` key
+    pub secret: Object,
+    /// the `key.ed25519.public` key
+    pub public: Object,
+    /// the unused KDF salt, opslimit and memlimit
+    kdf: [u8; 48],
+}
+
+/// A minisign signature file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    /// the untrusted comment
+    pub comment: String,
+    /// true if the signature is over the BLAKE2b-512 hash of the file
+    pub prehashed: bool,
+    /// the id of the key that made the signature
+    pub key_id: [u8; 8],
+    /// the signature of the file
+    pub signature: [u8; 64],
+    /// the trusted comment
+    pub trusted_comment: String,
+    /// the signature of the file signature and the trusted comment
+    pub global_signature: [u8; 64],
+}
+
+impl PublicKey {
+    /// Creates a public key file for the key with the comment minisign uses
+    pub fn new(key: Object, key_id: [u8; 8]) -> Result<Self> {
+        ed25519(&key, "key.ed25519.public")?;
+        Ok(PublicKey {
+            comment: format!("minisign public key {}", key_id_hex(&key_id)),
+            key_id,
+            key,
+        })
+    }
+}
+
+impl SecretKey {
+    /// Creates an unencrypted secret key file for the key pair
+    pub fn new(secret: Object, public: Object, key_id: [u8; 8]) -> Result<Self> {
+        ed25519(&secret, "key.ed25519.secret")?;
+        ed25519(&public, "key.ed25519.public")?;
+        Ok(SecretKey {
+            comment: "minisign secret key".to_string(),
+            key_id,
+            secret,
+            public,
+            kdf: [0u8; 48],
+        })
+    }
+
+    /// Returns the public key file for the secret key
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey {
+            comment: format!("minisign public key {}", key_id_hex(&self.key_id)),
+            key_id: self.key_id,
+            key: self.public.clone(),
+        }
+    }
+
+    fn checksum(&self) -> Vec<u8> {
+        let mut h = Blake2b::<U32>::new();
+        h.update(ED25519);
+        h.update(self.key_id);
+        h.update(self.secret.data());
+        h.update(self.public.data());
+        h.finalize().to_vec()
+    }
+}
+
+impl Signature {
+    /// Returns the signature as a `signature.minisign` object
+    pub fn to_object(&self) -> Result<Object> {
+        Object::from_type("signature.minisign", self.to_string().as_bytes())
+    }
+
+    /// Parses the signature file in a `signature.minisign` object
+    pub fn from_object(obj: &Object) -> Result<Self> {
+        if obj.tag().canonical_name() != "signature.minisign" {
+            return Err(Error::UnsupportedType(obj.tag().canonical_name()));
+        }
+        core::str::from_utf8(obj.data())
+            .map_err(|_| minisign_err("not utf-8"))?
+            .parse()
+    }
+}
+
+impl FromStr for PublicKey {
+    type Err = Error;
+
+    /// Parses a minisign public key file
+    fn from_str(s: &str) -> Result<Self> {
+        let (comment, b) = comment_and_data(&mut s.lines())?;
+        if b.len() != 42 || &b[..2] != ED25519 {
+            return Err(minisign_err("not an ed25519 public key"));
+        }
+        Ok(PublicKey {
+            comment,
+            key_id: array(&b[2..10]),
+            key: Object::from_type("key.ed25519.public", &b[10..])?,
+        })
+    }
+}
+
+impl FromStr for SecretKey {
+    type Err = Error;
+
+    /// Parses an unencrypted minisign secret key file
+    fn from_str(s: &str) -> Result<Self> {
+        let (comment, b) = comment_and_data(&mut s.lines())?;
+        if b.len() != 158 || &b[..2] != ED25519 || &b[4..6] != CHECKSUM_BLAKE2B {
+            return Err(minisign_err("not an ed25519 secret key"));
+        }
+        match &b[2..4] {
+            KDF_NONE => {}
+            KDF_SCRYPT => {
+                return Err(Error::UnsupportedAlgorithm(
+                    "encrypted minisign secret key".to_string(),
+                ))
+            }
+            _ => return Err(minisign_err("unknown KDF")),
+        }
+        let key = SecretKey {
+            comment,
+            kdf: array(&b[6..54]),
+            key_id: array(&b[54..62]),
+            secret: Object::from_type("key.ed25519.secret", &b[62..94])?,
+            public: Object::from_type("key.ed25519.public", &b[94..126])?,
+        };
+        if key.checksum() != b[126..] {
+            return Err(minisign_err("secret key checksum doesn't match"));
+        }
+        Ok(key)
+    }
+}
+
+impl FromStr for Signature {
+    type Err = Error;
+
+    /// Parses a minisign signature file
+    fn from_str(s: &str) -> Result<Self> {
+        let mut lines = s.lines();
+        let (comment, b) = comment_and_data(&mut lines)?;
+        let prehashed = match b.get(..2) {
+            Some(ED25519) => false,
+            Some(ED25519_PREHASHED) => true,
+            _ => return Err(minisign_err("not an ed25519 signature")),
+        };
+        if b.len() != 74 {
+            return Err(minisign_err("wrong signature size"));
+        }
+        let trusted_comment = lines
+            .next()
+            .and_then(|l| l.strip_prefix(TRUSTED))
+            .ok_or_else(|| minisign_err("missing trusted comment"))?;
+        let global = lines
+            .next()
+            .map(|l| BASE64.decode(l.trim().as_bytes()))
+            .ok_or_else(|| minisign_err("missing global signature"))??;
+        if global.len() != 64 {
+            return Err(minisign_err("wrong global signature size"));
+        }
+        Ok(Signature {
+            comment,
+            prehashed,
+            key_id: array(&b[2..10]),
+            signature: array(&b[10..]),
+            trusted_comment: trusted_comment.to_string(),
+            global_signature: array(&global),
+        })
+    }
+}
+
+impl Display for PublicKey {
+    /// Writes the minisign public key file
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let b = [ED25519, &self.key_id, self.key.data()].concat();
+        write!(f, "{}{}\n{}\n", UNTRUSTED, self.comment, BASE64.encode(&b))
+    }
+}
+
+impl Display for SecretKey {
+    /// Writes the unencrypted minisign secret key file
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let b = [
+            ED25519,
+            KDF_NONE,
+            CHECKSUM_BLAKE2B,
+            &self.kdf,
+            &self.key_id,
+            self.secret.data(),
+            self.public.data(),
+            &self.checksum(),
+        ]
+        .concat();
+        write!(f, "{}{}\n{}\n", UNTRUSTED, self.comment, BASE64.encode(&b))
+    }
+}
+
+impl Display for Signature {
+    /// Writes the minisign signature file
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let alg = if self.prehashed {
+            ED25519_PREHASHED
+        } else {
+            ED25519
+        };
+        let b = [alg, &self.key_id, &self.signature].concat();
+        write!(
+            f,
+            "{}{}\n{}\n{}{}\n{}\n",
+            UNTRUSTED,
+            self.comment,
+            BASE64.encode(&b),
+            TRUSTED,
+            self.trusted_comment,
+            BASE64.encode(&self.global_signature)
+        )
+    }
+}
+
+/// Reads the untrusted comment line and the base64 line after it
+fn comment_and_data<'a, I: Iterator<Item = &'a str>>(lines: &mut I) -> Result<(String, Vec<u8>)> {
+    let comment = lines
+        .next()
+        .and_then(|l| l.strip_prefix(UNTRUSTED))
+        .ok_or_else(|| minisign_err("missing untrusted comment"))?;
+    let b = lines
+        .next()
+        .map(|l| BASE64.decode(l.trim().as_bytes()))
+        .ok_or_else(|| minisign_err("missing key or signature"))??;
+    Ok((comment.to_string(), b))
+}
+
+fn ed25519(key: &Object, name: &str) -> Result<()> {
+    if key.tag().canonical_name() != name {
+        return Err(Error::UnsupportedType(key.tag().canonical_name()));
+    }
+    if key.data().len() != 32 {
+        return Err(Error::InvalidKey {
+            name: name.to_string(),
+            reason: "wrong key size".to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Returns the key id the way minisign prints it, as a little endian number
+fn key_id_hex(key_id: &[u8; 8]) -> String {
+    format!("{:016X}", u64::from_le_bytes(*key_id))
+}
+
+fn array<const N: usize>(b: &[u8]) -> [u8; N] {
+    let mut a = [0u8; N];
+    a.copy_from_slice(b);
+    a
+}
+
+fn minisign_err(reason: &str) -> Error {
+    Error::MinisignError(reason.to_string())
+}
@@ -0,0 +1,116 @@
+//! Multibase-style self-describing text and input format detection
+//!
+//! The prefixed form of an encoded object is the CDE text with a leading '~',
+//! a character that isn't assigned in the multibase table and isn't in the
+//! CDE alphabet, so prefixed CDE text can't be mistaken for anything else.
+use crate::{CryptoData, Error, Object, Result, CDE_ALPHABET, ENCODER};
+use data_encoding::{BASE64, BASE64_NOPAD, HEXLOWER_PERMISSIVE};
+
+/// The multibase-style prefix for CDE text
+pub const PREFIX: char = '~';
+
+/// The input formats the decoder recognises
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// CDE text with the '~' prefix
+    Prefixed,
+    /// CDE text without a prefix
+    Text,
+    /// the binary form of a CDE object
+    Binary,
+    /// hex encoded binary, optionally with a multibase 'f'/'F' or "0x" prefix
+    Hex,
+    /// standard base64 encoded binary, optionally with a multibase 'm'/'M'
+    /// prefix
+    Base64,
+}
+
+/// Decodes text to bytes if it is in the expected format
+type Decoder = fn(&[u8]) -> Option<Vec<u8>>;
+
+/// The text formats in the order they are tried, with their optional prefix
+static CANDIDATES: [(Format, &[u8], Decoder); 6] = [
+    (Format::Text, b"", cde_text),
+    (Format::Hex, b"", hex),
+    (Format::Hex, b"0x", hex),
+    (Format::Hex, b"f", hex),
+    (Format::Base64, b"", base64),
+    (Format::Base64, b"m", base64),
+];
+
+/// Returns the prefixed text form of the object
+pub fn encode(obj: &Object) -> String {
+    format!("{}{}", PREFIX, obj)
+}
+
+/// Decodes an object from any of the recognised input formats
+pub fn decode(input: &[u8]) -> Result<Object> {
+    detect(input).map(|(_, obj)| obj)
+}
+
+/// Detects the format of the input and decodes the object. Each format is
+/// tried in turn and the first one that decodes to a tag whose data length
+/// matches the rest of the input wins.
+pub fn detect(input: &[u8]) -> Result<(Format, Object)> {
+    let text = trim(input);
+
+    if let Some(rest) = text.strip_prefix(&[PREFIX as u8]) {
+        return Ok((Format::Prefixed, Object::from_encoded(rest)?));
+    }
+
+    for (format, prefix, decode) in CANDIDATES.iter() {
+        let obj = strip(text, prefix).and_then(decode).and_then(|b| exact(&b));
+        if let Some(obj) = obj {
+            return Ok((*format, obj));
+        }
+    }
+
+    match exact(input) {
+        Some(obj) => Ok((Format::Binary, obj)),
+        None => Err(Error::UnknownFormat),
+    }
+}
+
+/// Decodes the binary form of an object only if the data length in the tag
+/// accounts for every byte after the tag
+fn exact(b: &[u8]) -> Option<Object> {
+    let obj = Object::from_bytes(b).ok()?;
+    if obj.len() == b.len() {
+        Some(obj)
+    } else {
+        None
+    }
+}
+
+fn trim(b: &[u8]) -> &[u8] {
+    let start = b.iter().position(|c| !c.is_ascii_whitespace());
+    let end = b.iter().rposition(|c| !c.is_ascii_whitespace());
+    match (start, end) {
+        (Some(s), Some(e)) => &b[s..=e],
+        _ => &b[0..0],
+    }
+}
+
+fn cde_text(t: &[u8]) -> Option<Vec<u8>> {
+    if !t.iter().all(|c| CDE_ALPHABET.as_bytes().contains(c)) {
+        return None;
+    }
+    ENCODER.decode(t).ok()
+}
+
+/// Strips a prefix, multibase prefixes match in either case
+fn strip<'a>(t: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if t.len() >= prefix.len() && t[..prefix.len()].eq_ignore_ascii_case(prefix) {
+        Some(&t[prefix.len()..])
+    } else {
+        None
+    }
+}
+
+fn hex(t: &[u8]) -> Option<Vec<u8>> {
+    HEXLOWER_PERMISSIVE.decode(t).ok()
+}
+
+fn base64(t: &[u8]) -> Option<Vec<u8>> {
+    BASE64.decode(t).or_else(|_| BASE64_NOPAD.decode(t)).ok()
+}
@@ -0,0 +1,100 @@
+//! Translation between CDE type tags and multicodec codes
+//!
+//! A multicodec-prefixed byte string is the unsigned varint code followed by
+//! the same payload bytes as the CDE object.
+use crate::{Error, Object, Result, Tag, TagBuilder};
+
+/// The multicodec codes for every type in the registry that has one
+static CODES: [(u64, &str); 32] = [
+    // public keys
+    (0xed, "key.ed25519.public"),
+    (0xec, "key.x25519.public"),
+    (0xe7, "key.k256.public"),
+    (0x1200, "key.p256.public"),
+    (0x1205, "key.rsa.public"),
+    (0xeb, "key.bls12381.public"),
+    // secret keys
+    (0x1300, "key.ed25519.secret"),
+    (0x1302, "key.x25519.secret"),
+    (0x1301, "key.k256.secret"),
+    (0x1306, "key.p256.secret"),
+    (0x1305, "key.rsa.secret"),
+    (0x130a, "key.bls12381.secret"),
+    // symmetric keys
+    (0xa0, "key.aes.128"),
+    (0xa2, "key.aes.256"),
+    (0xa4, "key.chacha20"),
+    // digests
+    (0x11, "digest.sha1"),
+    (0x12, "digest.sha2.256"),
+    (0x13, "digest.sha2.512"),
+    (0x20, "digest.sha2.384"),
+    (0x1013, "digest.sha2.224"),
+    (0x1014, "digest.sha2.512/224"),
+    (0x1015, "digest.sha2.512/256"),
+    (0x14, "digest.sha3.512"),
+    (0x15, "digest.sha3.384"),
+    (0x16, "digest.sha3.256"),
+    (0x17, "digest.sha3.224"),
+    (0x18, "digest.sha3.shake128"),
+    (0x19, "digest.sha3.shake256"),
+    (0xd4, "digest.md.4"),
+    (0xd5, "digest.md.5"),
+    // blake2 digests use their full output size
+    (0xb240, "digest.blake2.b"),
+    (0xb260, "digest.blake2.s"),
+];
+
+/// Returns the multicodec code for the type of the tag, ignoring the length
+pub fn code(tag: &Tag) -> Option<u64> {
+    let tag = tag.with_data_length(0);
+    CODES
+        .iter()
+        .find(|(_, name)| TagBuilder::from_tag(name).build().ok() == Some(tag))
+        .map(|(code, _)| *code)
+}
+
+/// Returns the tag for the multicodec code
+pub fn tag(code: u64) -> Option<Tag> {
+    CODES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .and_then(|(_, name)| TagBuilder::from_tag(name).build().ok())
+}
+
+/// Encodes the object as a multicodec-prefixed byte string
+pub fn encode(obj: &Object) -> Result<Vec<u8>> {
+    let code = code(&obj.tag()).ok_or_else(|| Error::NoMulticodec(obj.tag().canonical_name()))?;
+    let mut b = Vec::with_capacity(10 + obj.data().len());
+    write_uvarint(code, &mut b);
+    b.extend_from_slice(obj.data());
+    Ok(b)
+}
+
+/// Decodes a multicodec-prefixed byte string into an object
+pub fn decode(b: &[u8]) -> Result<Object> {
+    let (code, len) = read_uvarint(b)?;
+    let tag = tag(code).ok_or(Error::UnknownMulticodec(code))?;
+    Ok(Object::new(tag, &b[len..]))
+}
+
+/// Appends the unsigned varint encoding of the value
+pub(crate) fn write_uvarint(mut v: u64, b: &mut Vec<u8>) {
+    while v >= 0x80 {
+        b.push((v as u8) | 0x80);
+        v >>= 7;
+    }
+    b.push(v as u8);
+}
+
+/// Reads an unsigned varint returning the value and the number of bytes read
+pub(crate) fn read_uvarint(b: &[u8]) -> Result<(u64, usize)> {
+    let mut v = 0u64;
+    for (i, byte) in b.iter().take(9).enumerate() {
+        v |= u64::from(byte & 0x7f) << (i * 7);
+        if byte & 0x80 == 0 {
+            return Ok((v, i + 1));
+        }
+    }
+    Err(Error::InvalidLength)
+}
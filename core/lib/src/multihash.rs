@@ -0,0 +1,107 @@
+//! Conversion between `digest.*` objects and multihashes
+//!
+//! A multihash is the unsigned varint hash function code, the unsigned varint
+//! digest length and then the digest bytes.
+use crate::{
+    multicodec::{read_uvarint, write_uvarint},
+    Error, Object, Result, Tag, TagBuilder,
+};
+
+/// The multihash code and output size in bytes for every digest in the
+/// registry that has one
+static DIGESTS: [(u64, &str, usize); 15] = [
+    (0x11, "digest.sha1", 20),
+    (0x12, "digest.sha2.256", 32),
+    (0x13, "digest.sha2.512", 64),
+    (0x20, "digest.sha2.384", 48),
+    (0x1013, "digest.sha2.224", 28),
+    (0x1014, "digest.sha2.512/224", 28),
+    (0x1015, "digest.sha2.512/256", 32),
+    (0x14, "digest.sha3.512", 64),
+    (0x15, "digest.sha3.384", 48),
+    (0x16, "digest.sha3.256", 32),
+    (0x17, "digest.sha3.224", 28),
+    (0x18, "digest.sha3.shake128", 32),
+    (0x19, "digest.sha3.shake256", 64),
+    (0xd4, "digest.md.4", 16),
+    (0xd5, "digest.md.5", 16),
+];
+
+/// blake2 digests have a code for every output size from 1 byte up to the
+/// maximum, blake2b-8 is 0xb201 through blake2b-512 at 0xb240 and blake2s-8
+/// is 0xb241 through blake2s-256 at 0xb260
+static BLAKE2: [(u64, &str, usize); 2] = [
+    (0xb200, "digest.blake2.b", 64),
+    (0xb240, "digest.blake2.s", 32),
+];
+
+fn build(name: &str) -> Tag {
+    TagBuilder::from_tag(name).build().unwrap_or_default()
+}
+
+/// Returns the multihash code for the digest type and length
+pub fn code(tag: &Tag, len: usize) -> Result<u64> {
+    let name = tag.canonical_name();
+    let tag = tag.with_data_length(0);
+    if let Some((code, _, size)) = DIGESTS.iter().find(|(_, n, _)| build(n) == tag) {
+        if len != *size {
+            return Err(Error::InvalidDigestLength {
+                name,
+                expected: *size,
+                actual: len,
+            });
+        }
+        return Ok(*code);
+    }
+    if let Some((base, _, max)) = BLAKE2.iter().find(|(_, n, _)| build(n) == tag) {
+        if !(1..=*max).contains(&len) {
+            return Err(Error::InvalidDigestLength {
+                name,
+                expected: *max,
+                actual: len,
+            });
+        }
+        return Ok(base + len as u64);
+    }
+    Err(Error::NoMultihash(name))
+}
+
+/// Returns the tag and the output size in bytes for the multihash code
+pub fn tag(code: u64) -> Option<(Tag, usize)> {
+    if let Some((_, name, size)) = DIGESTS.iter().find(|(c, _, _)| *c == code) {
+        return Some((build(name), *size));
+    }
+    BLAKE2
+        .iter()
+        .find(|(base, _, max)| code > *base && code <= base + *max as u64)
+        .map(|(base, name, _)| (build(name), (code - base) as usize))
+}
+
+/// Exports a `digest.*` object as a multihash
+pub fn encode(obj: &Object) -> Result<Vec<u8>> {
+    let code = code(&obj.tag(), obj.data().len())?;
+    let mut b = Vec::with_capacity(4 + obj.data().len());
+    write_uvarint(code, &mut b);
+    write_uvarint(obj.data().len() as u64, &mut b);
+    b.extend_from_slice(obj.data());
+    Ok(b)
+}
+
+/// Imports a multihash as a `digest.*` object
+pub fn decode(b: &[u8]) -> Result<Object> {
+    let (code, i) = read_uvarint(b)?;
+    let (len, j) = read_uvarint(&b[i..])?;
+    let (tag, size) = tag(code).ok_or(Error::UnknownMultihash(code))?;
+    if len as usize != size {
+        return Err(Error::InvalidDigestLength {
+            name: tag.canonical_name(),
+            expected: size,
+            actual: len as usize,
+        });
+    }
+    let digest = &b[i + j..];
+    if digest.len() != size {
+        return Err(Error::InvalidLength);
+    }
+    Ok(Object::new(tag, digest))
+}
@@ -0,0 +1,195 @@
+use crate::{idx, CryptoData, Error, Result, Tag, TagBuilder, ENCODER};
+use std::convert::TryFrom;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
+
+/// The deepest lists can be nested inside each other
+pub const MAX_LIST_DEPTH: usize = 32;
+
+/// An owned CDE object, a type tag and the data it describes. The data length
+/// in the tag is the length of the data, except for lists where it is the
+/// number of objects in the list and the data is the binary form of each
+/// object one after the other.
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Object {
+    tag: Tag,
+    data: Vec<u8>,
+}
+
+impl Object {
+    /// Creates a new object, setting the data length in the tag. Use
+    /// `Object::list` to create lists.
+    pub fn new(tag: Tag, data: &[u8]) -> Self {
+        Object {
+            tag: tag.with_data_length(data.len()),
+            data: data.to_vec(),
+        }
+    }
+
+    /// Creates a new object from a type string or alias and the data
+    pub fn from_type(tag: &str, data: &[u8]) -> Result<Self> {
+        Ok(Object::new(TagBuilder::from_tag(tag).build()?, data))
+    }
+
+    /// Creates a list object from a list tag like "key.list" and the items
+    pub fn list(tag: Tag, items: &[Object]) -> Self {
+        let mut data = Vec::new();
+        for item in items {
+            data.extend_from_slice(&item.to_bytes());
+        }
+        Object {
+            tag: tag.with_data_length(items.len()),
+            data,
+        }
+    }
+
+    /// Decodes an object from its binary form, the tag bytes followed by the
+    /// data. Any bytes after the data are an error, use `Object::parse` to
+    /// read an object from the front of a longer buffer.
+    pub fn from_bytes(b: &[u8]) -> Result<Self> {
+        match Object::parse(b)? {
+            (obj, len) if len == b.len() => Ok(obj),
+            _ => Err(Error::InvalidLength),
+        }
+    }
+
+    /// Decodes an object from the front of the buffer returning it and the
+    /// number of bytes it used, for reading objects stored one after another
+    pub fn parse(b: &[u8]) -> Result<(Self, usize)> {
+        Object::parse_nested(b, 0)
+    }
+
+    /// Parses an object inside `depth` lists, bounding the recursion so a
+    /// buffer of nested list tags can't overflow the stack
+    fn parse_nested(b: &[u8], depth: usize) -> Result<(Self, usize)> {
+        let tag = TagBuilder::from_bytes(b).build()?;
+        let start = tag.len();
+        let end = if tag.subclass() == idx('-') {
+            if depth == MAX_LIST_DEPTH {
+                return Err(Error::ListTooDeep(MAX_LIST_DEPTH));
+            }
+            // the items in a list each say how long they are
+            let mut end = start;
+            for _ in 0..tag.get_data_length() {
+                let rest = b.get(end..).ok_or(Error::InvalidLength)?;
+                end += Object::parse_nested(rest, depth + 1)?.1;
+            }
+            end
+        } else {
+            start
+                .checked_add(tag.get_data_length())
+                .ok_or(Error::InvalidLength)?
+        };
+        if b.len() < end {
+            return Err(Error::InvalidLength);
+        }
+        let obj = Object {
+            tag,
+            data: b[start..end].to_vec(),
+        };
+        Ok((obj, end))
+    }
+
+    /// Decodes an object from its text encoding
+    pub fn from_encoded(e: &[u8]) -> Result<Self> {
+        Object::from_bytes(&ENCODER.decode(e)?)
+    }
+
+    pub fn tag(&self) -> Tag {
+        self.tag
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Returns true if the object is a list of objects
+    pub fn is_list(&self) -> bool {
+        self.tag.subclass() == idx('-')
+    }
+
+    /// Returns the objects in a list
+    pub fn items(&self) -> Result<Vec<Object>> {
+        if !self.is_list() {
+            return Err(Error::UnsupportedType(self.tag.canonical_name()));
+        }
+        let mut items = Vec::with_capacity(self.tag.get_data_length());
+        let mut i = 0;
+        while i < self.data.len() {
+            let (item, len) = Object::parse(&self.data[i..])?;
+            items.push(item);
+            i += len;
+        }
+        if items.len() != self.tag.get_data_length() {
+            return Err(Error::InvalidLength);
+        }
+        Ok(items)
+    }
+
+    /// Returns the binary form of the object
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut b = vec![0u8; self.len()];
+        self.bytes(&mut b);
+        b
+    }
+}
+
+impl CryptoData for Object {
+    fn len(&self) -> usize {
+        self.tag.len() + self.data.len()
+    }
+
+    fn bytes(&self, buf: &mut [u8]) -> usize {
+        let len = self.tag.bytes(buf);
+        buf[len..len + self.data.len()].copy_from_slice(&self.data);
+        self.len()
+    }
+
+    fn encode_len(&self) -> usize {
+        ENCODER.encode_len(self.len())
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> usize {
+        ENCODER.encode_mut(&self.to_bytes(), &mut buf[..self.encode_len()]);
+        self.encode_len()
+    }
+}
+
+impl FromStr for Object {
+    type Err = Error;
+
+    /// Parses the text encoding of an object
+    fn from_str(s: &str) -> Result<Self> {
+        Object::from_encoded(s.as_bytes())
+    }
+}
+
+impl TryFrom<&[u8]> for Object {
+    type Error = Error;
+
+    /// Decodes the binary form of an object
+    fn try_from(b: &[u8]) -> Result<Self> {
+        Object::from_bytes(b)
+    }
+}
+
+impl Display for Object {
+    /// Writes the text encoding of the object
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&ENCODER.encode(&self.to_bytes()))
+    }
+}
+
+impl Debug for Object {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (", self.tag.canonical_name())?;
+        for b in &self.data {
+            write!(f, "{:02x}", b)?;
+        }
+        write!(f, ")")
+    }
+}
@@ -0,0 +1,77 @@
+//! PEM armor for CDE objects
+//!
+//! The armor label is derived from the type tag, "key.ed25519.secret" becomes
+//! "CDE KEY ED25519 SECRET", and the body is the CDE text encoding of the
+//! object wrapped at 64 columns. Labels are upper case like other PEM labels
+//! and are compared ignoring case, so experimental names like "Aead" share a
+//! label with the standard "aead" and the tag inside tells them apart.
+use crate::{Error, Object, Result, Tag};
+
+/// The number of characters in each line of the body
+const WIDTH: usize = 64;
+
+/// Returns the PEM label for objects with the tag
+pub fn label(tag: &Tag) -> String {
+    format!("CDE {}", tag.canonical_name().replace('.', " ")).to_ascii_uppercase()
+}
+
+/// Wraps the object in PEM armor
+pub fn encode(obj: &Object) -> String {
+    let label = label(&obj.tag());
+    let text = obj.to_string();
+    let mut s = format!("-----BEGIN {}-----\n", label);
+    for line in text.as_bytes().chunks(WIDTH) {
+        // the CDE text is all ascii so the chunks are always valid utf-8
+        s.push_str(core::str::from_utf8(line).unwrap_or_default());
+        s.push('\n');
+    }
+    s.push_str(&format!("-----END {}-----\n", label));
+    s
+}
+
+/// Parses the first PEM block in the text, checking that the armor label
+/// agrees with the type tag of the object inside
+pub fn decode(s: &str) -> Result<Object> {
+    let mut lines = s
+        .lines()
+        .map(str::trim)
+        .skip_while(|l| !l.starts_with("-----BEGIN "));
+
+    let begin = lines
+        .next()
+        .and_then(|l| armor(l, "BEGIN"))
+        .ok_or_else(|| Error::InvalidPem("missing BEGIN line".to_string()))?;
+
+    let mut text = String::new();
+    let end = loop {
+        match lines.next() {
+            None => return Err(Error::InvalidPem("missing END line".to_string())),
+            Some(l) if l.starts_with("-----END ") => break armor(l, "END"),
+            Some(l) => text.push_str(l),
+        }
+    };
+    if !end.map_or(false, |end| end.eq_ignore_ascii_case(begin)) {
+        return Err(Error::InvalidPem(format!(
+            "END label doesn't match BEGIN label '{}'",
+            begin
+        )));
+    }
+
+    let obj = Object::from_encoded(text.as_bytes())?;
+    if !begin.eq_ignore_ascii_case(&label(&obj.tag())) {
+        return Err(Error::PemLabelMismatch {
+            label: begin.to_string(),
+            name: obj.tag().canonical_name(),
+        });
+    }
+    Ok(obj)
+}
+
+/// Returns the label from a "-----BEGIN label-----" or "-----END label-----"
+/// line
+fn armor<'a>(line: &'a str, kind: &str) -> Option<&'a str> {
+    line.strip_prefix("-----")?
+        .strip_prefix(kind)?
+        .strip_prefix(' ')?
+        .strip_suffix("-----")
+}
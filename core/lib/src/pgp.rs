@@ -0,0 +1,297 @@
+//! OpenPGP signatures as `signature.pgp` objects
+//!
+//! The data of a `signature.pgp` object is one or more binary OpenPGP
+//! signature packets (RFC 4880 and RFC 9580), the same bytes as a detached
+//! `.sig` file. The packet framing is checked when the object is created and
+//! the ASCII armor is added back when it is extracted.
+use crate::{Error, Object, Result};
+use data_encoding::BASE64;
+
+const BEGIN: &str = "-----BEGIN PGP SIGNATURE-----";
+const END: &str = "-----END PGP SIGNATURE-----";
+
+/// The number of characters in each line of the armored body
+const WIDTH: usize = 64;
+
+/// The packet tag of signature packets
+const SIGNATURE: u8 = 2;
+
+// signature subpacket types
+const ISSUER: u8 = 16;
+const ISSUER_FINGERPRINT: u8 = 33;
+
+/// The OpenPGP packet type names by packet tag
+static PACKETS: [(u8, &str); 18] = [
+    (1, "Public-Key Encrypted Session Key"),
+    (2, "Signature"),
+    (3, "Symmetric-Key Encrypted Session Key"),
+    (4, "One-Pass Signature"),
+    (5, "Secret-Key"),
+    (6, "Public-Key"),
+    (7, "Secret-Subkey"),
+    (8, "Compressed Data"),
+    (9, "Symmetrically Encrypted Data"),
+    (10, "Marker"),
+    (11, "Literal Data"),
+    (12, "Trust"),
+    (13, "User ID"),
+    (14, "Public-Subkey"),
+    (17, "User Attribute"),
+    (18, "Symmetrically Encrypted and Integrity Protected Data"),
+    (19, "Modification Detection Code"),
+    (20, "AEAD Encrypted Data"),
+];
+
+/// What a signature packet says about itself
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SignatureInfo {
+    /// the packet tag, always 2
+    pub tag: u8,
+    /// the signature packet version
+    pub version: u8,
+    /// the signature type, 0x00 for a signature of a binary document
+    pub signature_type: u8,
+    /// the public key algorithm id
+    pub public_key_algorithm: u8,
+    /// the hash algorithm id
+    pub hash_algorithm: u8,
+    /// the key ID of the key that made the signature if the packet has one
+    pub issuer: Option<[u8; 8]>,
+}
+
+/// Returns the name of the OpenPGP packet type for the packet tag
+pub fn packet_name(tag: u8) -> &'static str {
+    PACKETS
+        .iter()
+        .find(|(t, _)| *t == tag)
+        .map_or("Unknown", |(_, name)| name)
+}
+
+/// Creates a `signature.pgp` object from binary signature packets
+pub fn from_binary(b: &[u8]) -> Result<Object> {
+    for (tag, _) in packets(b)? {
+        if tag != SIGNATURE {
+            return Err(pgp_err(&format!("{} packet", packet_name(tag))));
+        }
+    }
+    Object::from_type("signature.pgp", b)
+}
+
+/// Creates a `signature.pgp` object from an ASCII-armored signature
+pub fn from_armor(s: &str) -> Result<Object> {
+    let mut lines = s
+        .lines()
+        .map(str::trim_end)
+        .skip_while(|l| *l != BEGIN)
+        .skip(1);
+
+    // the armor headers end with a blank line
+    for line in &mut lines {
+        if line.is_empty() {
+            break;
+        }
+        if !line.contains(": ") {
+            return Err(pgp_err("invalid armor header"));
+        }
+    }
+
+    let mut text = String::new();
+    let mut crc = None;
+    loop {
+        match lines.next() {
+            None => return Err(pgp_err("missing END line")),
+            Some(END) => break,
+            Some(l) if l.starts_with('=') => crc = Some(BASE64.decode(&l.as_bytes()[1..])?),
+            Some(l) => text.push_str(l),
+        }
+    }
+    let b = BASE64.decode(text.as_bytes())?;
+    // the checksum is optional
+    if let Some(crc) = crc {
+        if crc != crc24(&b) {
+            return Err(pgp_err("armor checksum doesn't match"));
+        }
+    }
+    from_binary(&b)
+}
+
+/// Returns the ASCII-armored signature in a `signature.pgp` object
+pub fn to_armor(obj: &Object) -> Result<String> {
+    check(obj)?;
+    let mut s = format!("{}\n\n", BEGIN);
+    for line in BASE64.encode(obj.data()).as_bytes().chunks(WIDTH) {
+        // base64 is all ascii so the chunks are always valid utf-8
+        s.push_str(core::str::from_utf8(line).unwrap_or_default());
+        s.push('\n');
+    }
+    s.push_str(&format!(
+        "={}\n{}\n",
+        BASE64.encode(&crc24(obj.data())),
+        END
+    ));
+    Ok(s)
+}
+
+/// Returns what each signature packet in a `signature.pgp` object says about
+/// itself
+pub fn inspect(obj: &Object) -> Result<Vec<SignatureInfo>> {
+    check(obj)?;
+    packets(obj.data())?
+        .into_iter()
+        .map(|(tag, body)| signature_info(tag, body))
+        .collect()
+}
+
+fn check(obj: &Object) -> Result<()> {
+    if obj.tag().canonical_name() != "signature.pgp" {
+        return Err(Error::UnsupportedType(obj.tag().canonical_name()));
+    }
+    Ok(())
+}
+
+/// Splits the bytes into packet tags and bodies, the packets must account for
+/// every byte
+fn packets(mut b: &[u8]) -> Result<Vec<(u8, &[u8])>> {
+    let mut packets = Vec::new();
+    if b.is_empty() {
+        return Err(pgp_err("no packets"));
+    }
+    while !b.is_empty() {
+        let ctb = b[0];
+        if ctb & 0x80 == 0 {
+            return Err(pgp_err("invalid packet header"));
+        }
+        let (tag, start, len) = if ctb & 0x40 != 0 {
+            // new format
+            match *b.get(1).ok_or_else(truncated)? {
+                o if o < 192 => (ctb & 0x3f, 2, o as usize),
+                o if o < 224 => {
+                    let o2 = *b.get(2).ok_or_else(truncated)?;
+                    (ctb & 0x3f, 3, ((o as usize - 192) << 8) + o2 as usize + 192)
+                }
+                255 => (ctb & 0x3f, 6, be(b.get(2..6).ok_or_else(truncated)?)),
+                _ => return Err(pgp_err("partial body lengths aren't allowed")),
+            }
+        } else {
+            // old format
+            let tag = (ctb >> 2) & 0x0f;
+            match ctb & 0x03 {
+                0 => (tag, 2, be(b.get(1..2).ok_or_else(truncated)?)),
+                1 => (tag, 3, be(b.get(1..3).ok_or_else(truncated)?)),
+                2 => (tag, 5, be(b.get(1..5).ok_or_else(truncated)?)),
+                _ => return Err(pgp_err("indeterminate lengths aren't allowed")),
+            }
+        };
+        let body = slice(b, start, len)?;
+        packets.push((tag, body));
+        b = &b[start + len..];
+    }
+    Ok(packets)
+}
+
+/// Reads the version, algorithms and issuer from a signature packet body
+fn signature_info(tag: u8, b: &[u8]) -> Result<SignatureInfo> {
+    let byte = |i: usize| b.get(i).copied().ok_or_else(truncated);
+    let version = byte(0)?;
+    let mut info = SignatureInfo {
+        tag,
+        version,
+        signature_type: 0,
+        public_key_algorithm: 0,
+        hash_algorithm: 0,
+        issuer: None,
+    };
+    match version {
+        3 => {
+            info.signature_type = byte(2)?;
+            info.issuer = Some(array(b.get(7..15).ok_or_else(truncated)?));
+            info.public_key_algorithm = byte(15)?;
+            info.hash_algorithm = byte(16)?;
+        }
+        4..=6 => {
+            info.signature_type = byte(1)?;
+            info.public_key_algorithm = byte(2)?;
+            info.hash_algorithm = byte(3)?;
+            // version 6 has four octet subpacket area lengths
+            let size = if version == 6 { 4 } else { 2 };
+            let mut i = 4;
+            for _ in 0..2 {
+                let len = be(b.get(i..i + size).ok_or_else(truncated)?);
+                let area = slice(b, i + size, len)?;
+                info.issuer = info.issuer.or(issuer(area)?);
+                i += size + len;
+            }
+        }
+        v => return Err(pgp_err(&format!("signature version {}", v))),
+    }
+    Ok(info)
+}
+
+/// Finds the issuer key ID in a signature subpacket area
+fn issuer(mut b: &[u8]) -> Result<Option<[u8; 8]>> {
+    let mut fingerprint = None;
+    while !b.is_empty() {
+        let (start, len) = match b[0] {
+            o if o < 192 => (1, o as usize),
+            o if o < 255 => (
+                2,
+                ((o as usize - 192) << 8) + *b.get(1).ok_or_else(truncated)? as usize + 192,
+            ),
+            _ => (5, be(b.get(1..5).ok_or_else(truncated)?)),
+        };
+        let sub = slice(b, start, len)?;
+        match (sub.first().map(|t| t & 0x7f), sub.len()) {
+            (Some(ISSUER), 9) => return Ok(Some(array(&sub[1..]))),
+            // a version 4 fingerprint ends with the key ID, later ones start
+            // with it
+            (Some(ISSUER_FINGERPRINT), 22) => fingerprint = Some(array(&sub[14..])),
+            (Some(ISSUER_FINGERPRINT), 34) => fingerprint = Some(array(&sub[2..10])),
+            _ => {}
+        }
+        b = &b[start + len..];
+    }
+    Ok(fingerprint)
+}
+
+/// The CRC-24 from RFC 4880 section 6.1
+fn crc24(b: &[u8]) -> Vec<u8> {
+    let mut crc: u32 = 0x00b7_04ce;
+    for byte in b {
+        crc ^= (*byte as u32) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x0100_0000 != 0 {
+                crc ^= 0x0186_4cfb;
+            }
+        }
+    }
+    crc.to_be_bytes()[1..].to_vec()
+}
+
+/// Reads a big endian length
+fn be(b: &[u8]) -> usize {
+    b.iter().fold(0, |n, x| (n << 8) | *x as usize)
+}
+
+fn array(b: &[u8]) -> [u8; 8] {
+    let mut a = [0u8; 8];
+    a.copy_from_slice(b);
+    a
+}
+
+/// Returns the `len` bytes at `start`, the lengths come from the packet so the
+/// end can overflow as well as run past the buffer
+fn slice(b: &[u8], start: usize, len: usize) -> Result<&[u8]> {
+    start
+        .checked_add(len)
+        .and_then(|end| b.get(start..end))
+        .ok_or_else(truncated)
+}
+
+fn truncated() -> Error {
+    pgp_err("truncated packet")
+}
+
+fn pgp_err(reason: &str) -> Error {
+    Error::InvalidPgp(reason.to_string())
+}
@@ -0,0 +1,65 @@
+//! Conversions between CDE rsa keys and the rsa crate key types
+//!
+//! The `key.rsa.secret` and `key.rsa.public` objects hold PKCS#1
+//! `RSAPrivateKey` and `RSAPublicKey` DER. The secret key is checked for
+//! consistency when it is converted.
+use crate::{
+    key::{self, Algorithm, Half},
+    Error, Object, Result,
+};
+use rsa::{
+    pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey, EncodeRsaPrivateKey, EncodeRsaPublicKey},
+    RsaPrivateKey, RsaPublicKey,
+};
+use std::convert::TryFrom;
+
+impl TryFrom<&Object> for RsaPrivateKey {
+    type Error = Error;
+
+    /// Creates a private key from a `key.rsa.secret` object
+    fn try_from(obj: &Object) -> Result<Self> {
+        key::expect(obj, Algorithm::Rsa, Half::Secret)?;
+        let key = RsaPrivateKey::from_pkcs1_der(obj.data())
+            .map_err(|e| key::invalid(obj.tag(), &e.to_string()))?;
+        key.validate()
+            .map_err(|e| key::invalid(obj.tag(), &e.to_string()))?;
+        Ok(key)
+    }
+}
+
+impl TryFrom<&Object> for RsaPublicKey {
+    type Error = Error;
+
+    /// Creates a public key from a `key.rsa.public` object
+    fn try_from(obj: &Object) -> Result<Self> {
+        key::expect(obj, Algorithm::Rsa, Half::Public)?;
+        RsaPublicKey::from_pkcs1_der(obj.data())
+            .map_err(|e| key::invalid(obj.tag(), &e.to_string()))
+    }
+}
+
+impl TryFrom<&RsaPrivateKey> for Object {
+    type Error = Error;
+
+    /// Creates a `key.rsa.secret` object from a private key
+    fn try_from(key: &RsaPrivateKey) -> Result<Self> {
+        let tag = Algorithm::Rsa.tag(Half::Secret);
+        let der = key
+            .to_pkcs1_der()
+            .map_err(|e| key::invalid(tag, &e.to_string()))?;
+        Ok(Object::new(tag, der.as_bytes()))
+    }
+}
+
+impl TryFrom<&RsaPublicKey> for Object {
+    type Error = Error;
+
+    /// Creates a `key.rsa.public` object from a public key
+    fn try_from(key: &RsaPublicKey) -> Result<Self> {
+        let tag = Algorithm::Rsa.tag(Half::Public);
+        let der = key
+            .to_pkcs1_der()
+            .map_err(|e| key::invalid(tag, &e.to_string()))?;
+        Ok(Object::new(tag, der.as_bytes()))
+    }
+}
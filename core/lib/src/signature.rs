@@ -0,0 +1,114 @@
+//! Signing and verifying with CDE keys
+//!
+//! The algorithm comes from the tag of the key. The signature types are still
+//! experimental: the ed25519 keys make `signature.Ed25519` objects holding the
+//! 64 byte signature, the p256 and k256 keys make `signature.Ecdsa.p256` and
+//! `signature.Ecdsa.k256` objects holding the 64 byte fixed size r and s of an
+//! ECDSA signature over the SHA-256 hash, and the rsa keys make
+//! `signature.Rsa` objects holding a PKCS#1 v1.5 signature over the SHA-256
+//! hash. Each algorithm needs the backend feature of the same name.
+use crate::{
+    key::{self, Algorithm, Half},
+    Error, Object, Result,
+};
+use core::convert::TryFrom;
+
+/// Signs the message with the secret key
+pub fn sign(secret: &Object, msg: &[u8]) -> Result<Object> {
+    let (alg, half) = key::check(secret)?;
+    if half != Half::Secret {
+        return Err(key::invalid(secret.tag(), "not a secret key"));
+    }
+    let sig = match alg {
+        #[cfg(feature = "ed25519")]
+        Algorithm::Ed25519 => {
+            let key = ed25519_dalek::SigningKey::try_from(secret)?;
+            ed25519_dalek::Signer::sign(&key, msg).to_vec()
+        }
+        #[cfg(feature = "p256")]
+        Algorithm::P256 => {
+            let key = p256::ecdsa::SigningKey::try_from(secret)?;
+            let sig: p256::ecdsa::Signature = p256::ecdsa::signature::Signer::sign(&key, msg);
+            sig.to_vec()
+        }
+        #[cfg(feature = "k256")]
+        Algorithm::K256 => {
+            let key = k256::ecdsa::SigningKey::try_from(secret)?;
+            let sig: k256::ecdsa::Signature = k256::ecdsa::signature::Signer::sign(&key, msg);
+            sig.to_vec()
+        }
+        #[cfg(feature = "rsa")]
+        Algorithm::Rsa => {
+            use rsa::signature::SignatureEncoding;
+            let key = rsa::pkcs1v15::SigningKey::<rsa::sha2::Sha256>::new(
+                rsa::RsaPrivateKey::try_from(secret)?,
+            );
+            rsa::signature::Signer::sign(&key, msg).to_vec()
+        }
+        _ => return Err(unsupported(secret)),
+    };
+    Object::from_type(signature_name(alg).unwrap_or_default(), &sig)
+}
+
+/// Verifies the signature of the message with the public key, a signature
+/// that doesn't match gives a `BadSignature` error
+pub fn verify(public: &Object, sig: &Object, msg: &[u8]) -> Result<()> {
+    let (alg, half) = key::check(public)?;
+    if half != Half::Public {
+        return Err(key::invalid(public.tag(), "not a public key"));
+    }
+    if Some(sig.tag().canonical_name().as_str()) != signature_name(alg) {
+        return Err(Error::UnsupportedType(sig.tag().canonical_name()));
+    }
+    let sig = sig.data();
+    match alg {
+        #[cfg(feature = "ed25519")]
+        Algorithm::Ed25519 => {
+            let key = ed25519_dalek::VerifyingKey::try_from(public)?;
+            let sig = ed25519_dalek::Signature::from_slice(sig).map_err(invalid)?;
+            ed25519_dalek::Verifier::verify(&key, msg, &sig).map_err(|_| Error::BadSignature)
+        }
+        #[cfg(feature = "p256")]
+        Algorithm::P256 => {
+            let key = p256::ecdsa::VerifyingKey::try_from(public)?;
+            let sig = p256::ecdsa::Signature::from_slice(sig).map_err(invalid)?;
+            p256::ecdsa::signature::Verifier::verify(&key, msg, &sig)
+                .map_err(|_| Error::BadSignature)
+        }
+        #[cfg(feature = "k256")]
+        Algorithm::K256 => {
+            let key = k256::ecdsa::VerifyingKey::try_from(public)?;
+            let sig = k256::ecdsa::Signature::from_slice(sig).map_err(invalid)?;
+            k256::ecdsa::signature::Verifier::verify(&key, msg, &sig)
+                .map_err(|_| Error::BadSignature)
+        }
+        #[cfg(feature = "rsa")]
+        Algorithm::Rsa => {
+            let key = rsa::pkcs1v15::VerifyingKey::<rsa::sha2::Sha256>::new(
+                rsa::RsaPublicKey::try_from(public)?,
+            );
+            let sig = rsa::pkcs1v15::Signature::try_from(sig).map_err(invalid)?;
+            rsa::signature::Verifier::verify(&key, msg, &sig).map_err(|_| Error::BadSignature)
+        }
+        _ => Err(unsupported(public)),
+    }
+}
+
+/// Returns the signature type made by keys of the algorithm
+fn signature_name(alg: Algorithm) -> Option<&'static str> {
+    match alg {
+        Algorithm::Ed25519 => Some("signature.Ed25519"),
+        Algorithm::P256 => Some("signature.Ecdsa.p256"),
+        Algorithm::K256 => Some("signature.Ecdsa.k256"),
+        Algorithm::Rsa => Some("signature.Rsa"),
+        Algorithm::X25519 => None,
+    }
+}
+
+fn unsupported(key: &Object) -> Error {
+    Error::UnsupportedAlgorithm(format!("signing with {}", key.tag().canonical_name()))
+}
+
+fn invalid<E: core::fmt::Display>(e: E) -> Error {
+    Error::InvalidSignature(e.to_string())
+}
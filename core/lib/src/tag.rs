@@ -1,13 +1,21 @@
-use crate::{idx, CryptoData, Error, Result, VarUInt, CDE_ALPHABET, ENCODER};
+use crate::{idx, CryptoData, Error, Reason, Result, VarUInt, CDE_ALPHABET, ENCODER};
+use data_encoding::DecodeKind;
+use std::convert::TryFrom;
 use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
 
 // include the generated hashmaps
 include!(concat!(env!("OUT_DIR"), "/hashmaps.rs"));
 
-static NUMBERS: &'static str = "0123456789";
-static UNDEFINED: &'static str = "undefined";
+static NUMBERS: &str = "0123456789";
+static UNDEFINED: &str = "undefined";
 
-#[derive(Clone, Copy, Default, PartialEq)]
+/// Equality, hashing and ordering cover the data length as well as the type,
+/// so "key.ed25519.public" tags for 32 and 64 bytes of data are different
+/// keys in a map. Tags order by class, sub-class and sub-sub-class first and
+/// then by data length. Clear the length with `with_data_length(0)` to compare
+/// only the types.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Tag {
     b: [u8; 2],
     l: VarUInt,
@@ -21,6 +29,12 @@ impl Tag {
         t
     }
 
+    /// Parses a tag from the start of its text encoding (e.g. "keeA...").
+    /// Any encoded data following the tag is ignored.
+    pub fn from_encoded(e: &[u8]) -> Result<Self> {
+        TagBuilder::from_encoded(e).build()
+    }
+
     pub fn set_data_length(&mut self, len: usize) {
         self.l = VarUInt::from(len);
     }
@@ -41,7 +55,7 @@ impl Tag {
         if exp {
             self.b[0] |= 0x80;
         } else {
-            self.b[0] &= 0xf7;
+            self.b[0] &= 0x7f;
         }
     }
 
@@ -63,26 +77,43 @@ impl Tag {
                     //NOTE: due to a bug in the phf maps, we cannot use 0-indexing
                     //so we use 1-indexing instead as a work-around
                     if let Some(ssc) = ssc_map.get(&(n[2] + 1)) {
-                        return Ok((c, sc, Some(*ssc)));
+                        Ok((c, sc, Some(*ssc)))
                     } else {
-                        return Ok((c, sc, NUMBERS.get(i[2]..i[2] + 1)));
+                        Ok((c, sc, NUMBERS.get(i[2]..i[2] + 1)))
                     }
                 } else {
-                    return Ok((c, sc, NUMBERS.get(i[2]..i[2] + 1)));
+                    Ok((c, sc, NUMBERS.get(i[2]..i[2] + 1)))
                 }
             } else if let Some(sc) = CDE_ALPHABET.get(i[1]..i[1] + 1) {
-                return Ok((c, sc, None));
+                Ok((c, sc, None))
             } else {
-                return Ok((c, UNDEFINED, None));
+                Ok((c, UNDEFINED, None))
             }
         } else if let Some(c) = CDE_ALPHABET.get(i[0]..i[0] + 1) {
             if let Some(sc) = CDE_ALPHABET.get(i[1]..i[1] + 1) {
-                return Ok((c, sc, None));
+                Ok((c, sc, None))
             } else {
-                return Ok((c, UNDEFINED, None));
+                Ok((c, UNDEFINED, None))
             }
         } else {
-            return Ok((UNDEFINED, UNDEFINED, None));
+            Ok((UNDEFINED, UNDEFINED, None))
+        }
+    }
+
+    /// Returns the dotted type name for this tag. Unlike the Display output,
+    /// only includes the sub-sub-class when it is needed so the name always
+    /// parses back to the same tag. Aliases are never returned.
+    pub fn canonical_name(&self) -> String {
+        let (c, sc, ssc) = self.name().unwrap_or((UNDEFINED, UNDEFINED, None));
+        let named = NAMES
+            .get(&self.class())
+            .and_then(|(_, sc_map)| sc_map.get(&self.subclass()))
+            .map(|(_, ssc_map)| ssc_map.is_some())
+            .unwrap_or(false);
+        match ssc {
+            Some(ssc) if named => format!("{}.{}.{}", c, sc, ssc),
+            _ if self.subsubclass() != 0 => format!("{}.{}.{}", c, sc, self.subsubclass()),
+            _ => format!("{}.{}", c, sc),
         }
     }
 
@@ -97,6 +128,158 @@ impl Tag {
     pub fn subsubclass(&self) -> u8 {
         self.b[1] & 0x0f
     }
+
+    /// Sets the six bit class value, including its experimental bit. A
+    /// standard class must be in the registry and define the current
+    /// sub-class, any other class must be experimental. The tag is unchanged
+    /// when the new type isn't valid.
+    pub fn set_class(&mut self, class: u8) -> Result<()> {
+        *self = self.with_raw_class(class)?.validated()?;
+        Ok(())
+    }
+
+    /// Sets the six bit sub-class value, including its experimental bit. A
+    /// standard class only takes the sub-classes the registry defines for it.
+    pub fn set_subclass(&mut self, subclass: u8) -> Result<()> {
+        *self = self.with_raw_subclass(subclass)?.validated()?;
+        Ok(())
+    }
+
+    /// Sets the four bit sub-sub-class value. A standard sub-class only takes
+    /// the sub-sub-classes the registry defines for it.
+    pub fn set_subsubclass(&mut self, subsubclass: u8) -> Result<()> {
+        *self = self.with_raw_subsubclass(subsubclass)?.validated()?;
+        Ok(())
+    }
+
+    /// Builder form of `set_class`
+    pub fn with_class(mut self, class: u8) -> Result<Self> {
+        self.set_class(class)?;
+        Ok(self)
+    }
+
+    /// Builder form of `set_subclass`
+    pub fn with_subclass(mut self, subclass: u8) -> Result<Self> {
+        self.set_subclass(subclass)?;
+        Ok(self)
+    }
+
+    /// Builder form of `set_subsubclass`
+    pub fn with_subsubclass(mut self, subsubclass: u8) -> Result<Self> {
+        self.set_subsubclass(subsubclass)?;
+        Ok(self)
+    }
+
+    /// Checks the type against the registry with the rules for type strings,
+    /// the error names the first part that breaks them
+    fn validated(self) -> Result<Self> {
+        let (c, sc, ssc) = (self.class(), self.subclass(), self.subsubclass());
+        let experimental = |v: u8| (v > 31) && (v != 63);
+
+        let subclasses = NAMES.get(&c).map(|(_, subclasses)| subclasses);
+        if subclasses.is_none() && !experimental(c) {
+            return Err(class_err(&c.to_string(), Reason::NotExperimental));
+        }
+        let subsubclasses = match subclasses.and_then(|subclasses| subclasses.get(&sc)) {
+            Some((_, subsubclasses)) => *subsubclasses,
+            None if subclasses.is_some() && !experimental(c) => {
+                return Err(subclass_err(&sc.to_string(), Reason::ParentNotExperimental));
+            }
+            None if !experimental(sc) => {
+                return Err(subclass_err(&sc.to_string(), Reason::NotExperimental));
+            }
+            None => None,
+        };
+        // an experimental class only takes a non-experimental sub-class when
+        // there is no sub-sub-class
+        if experimental(c) && !experimental(sc) && (ssc != 0 || subsubclasses.is_some()) {
+            return Err(subclass_err(&sc.to_string(), Reason::ParentExperimental));
+        }
+
+        let allowed = match subsubclasses {
+            // the sub-sub-class maps are 1-indexed
+            Some(subsubclasses) => subsubclasses.contains_key(&(ssc + 1)),
+            None => {
+                ssc == 0
+                    || experimental(sc)
+                    || NUMBERED
+                        .iter()
+                        .any(|(nc, nsc)| c == idx(*nc) && sc == idx(*nsc))
+            }
+        };
+        if !allowed {
+            return Err(subsubclass_err(&ssc.to_string(), Reason::NotAllowed));
+        }
+        Ok(self)
+    }
+
+    /// Sets the raw six bit class value, including its experimental bit. Only
+    /// the width is checked, the resulting type may not be in the registry,
+    /// so this is only for building types `set_class` rejects.
+    pub fn set_raw_class(&mut self, class: u8) -> Result<()> {
+        if class > 0x3f {
+            return Err(class_err(&class.to_string(), Reason::OutOfRange));
+        }
+        self.b[0] = (self.b[0] & 0x03) | (class << 2);
+        Ok(())
+    }
+
+    /// Sets the raw six bit sub-class value, including its experimental bit.
+    /// Like `set_raw_class` this doesn't check the registry.
+    pub fn set_raw_subclass(&mut self, subclass: u8) -> Result<()> {
+        if subclass > 0x3f {
+            return Err(subclass_err(&subclass.to_string(), Reason::OutOfRange));
+        }
+        self.b[0] = (self.b[0] & 0xfc) | (subclass >> 4);
+        self.b[1] = (self.b[1] & 0x0f) | ((subclass & 0x0f) << 4);
+        Ok(())
+    }
+
+    /// Sets the raw four bit sub-sub-class value. Like `set_raw_class` this
+    /// doesn't check the registry.
+    pub fn set_raw_subsubclass(&mut self, subsubclass: u8) -> Result<()> {
+        if subsubclass > 0x0f {
+            return Err(subsubclass_err(
+                &subsubclass.to_string(),
+                Reason::OutOfRange,
+            ));
+        }
+        self.b[1] = (self.b[1] & 0xf0) | subsubclass;
+        Ok(())
+    }
+
+    /// Builder form of `set_raw_class`
+    pub fn with_raw_class(mut self, class: u8) -> Result<Self> {
+        self.set_raw_class(class)?;
+        Ok(self)
+    }
+
+    /// Builder form of `set_raw_subclass`
+    pub fn with_raw_subclass(mut self, subclass: u8) -> Result<Self> {
+        self.set_raw_subclass(subclass)?;
+        Ok(self)
+    }
+
+    /// Builder form of `set_raw_subsubclass`
+    pub fn with_raw_subsubclass(mut self, subsubclass: u8) -> Result<Self> {
+        self.set_raw_subsubclass(subsubclass)?;
+        Ok(self)
+    }
+
+    pub fn with_exp_class(mut self, exp: bool) -> Self {
+        self.set_exp_class(exp);
+        self
+    }
+
+    pub fn with_exp_sub_class(mut self, exp: bool) -> Self {
+        self.set_exp_sub_class(exp);
+        self
+    }
+
+    pub fn with_data_length(mut self, len: usize) -> Self {
+        self.set_data_length(len);
+        self
+    }
 }
 
 impl CryptoData for Tag {
@@ -132,6 +315,7 @@ pub struct TagBuilder<'a> {
     how: TagBuildFrom,
     tag: Option<&'a str>,
     bytes: Option<&'a [u8]>,
+    ignore_case: bool,
 }
 
 // create a tag in the provided buffer copying from the bytes slice
@@ -146,6 +330,7 @@ impl<'a> TagBuilder<'a> {
             how: TagBuildFrom::Tag,
             tag: Some(s),
             bytes: None,
+            ignore_case: false,
         }
     }
 
@@ -154,6 +339,7 @@ impl<'a> TagBuilder<'a> {
             how: TagBuildFrom::Bytes,
             tag: None,
             bytes: Some(b),
+            ignore_case: false,
         }
     }
 
@@ -162,18 +348,28 @@ impl<'a> TagBuilder<'a> {
             how: TagBuildFrom::Encoded,
             tag: None,
             bytes: Some(e),
+            ignore_case: false,
         }
     }
 
+    /// When set, standard names in a type string that don't match exactly are
+    /// matched again in lower case, so "KEY.ED25519.PUBLIC" parses the same as
+    /// "key.ed25519.public". Aliases such as "SHA-256" always match in any
+    /// case. Experimental names still need their exact case.
+    pub fn ignore_case(mut self, ignore_case: bool) -> Self {
+        self.ignore_case = ignore_case;
+        self
+    }
+
     pub fn build(&self) -> Result<Tag> {
         let mut buf = [0u8; 9];
         let tag = match self.how {
             TagBuildFrom::Tag => {
                 if let Some(tag) = self.tag {
-                    TagBuilder::decode_str(tag, &mut buf)?;
+                    TagBuilder::decode_str(tag, self.ignore_case, &mut buf)?;
                     Tag::new(&buf)
                 } else {
-                    return Err(Error::FromStr);
+                    return Err(class_err("", Reason::Missing));
                 }
             }
             TagBuildFrom::Bytes => {
@@ -181,8 +377,8 @@ impl<'a> TagBuilder<'a> {
                     if bytes.len() < 3 {
                         return Err(Error::InvalidLength);
                     } else {
-                        if let Ok(_) = VarUInt::try_parse(&bytes[2..]) {
-                            Tag::new(&bytes)
+                        if VarUInt::try_parse(&bytes[2..]).is_ok() {
+                            Tag::new(bytes)
                         } else {
                             return Err(Error::InvalidLength);
                         }
@@ -192,323 +388,401 @@ impl<'a> TagBuilder<'a> {
                 }
             }
             TagBuildFrom::Encoded => {
-                if let Some(bytes) = self.bytes {
-                    ENCODER
-                        .decode_mut(&bytes[0..4], &mut buf[0..3])
-                        .map_err(|_| Error::DecodeError)?;
-                    if (buf[2] & 0x80) != 0 {
-                        ENCODER
-                            .decode_mut(&bytes[4..8], &mut buf[3..6])
-                            .map_err(|_| Error::DecodeError)?;
+                let bytes = self.bytes.unwrap_or_default();
+                // the tag is one to three encoding units long. the top bit of
+                // the last byte in each decoded unit signals another unit
+                for unit in 0..3 {
+                    let (e, d) = (unit * 4, unit * 3);
+                    if unit > 0 && (buf[d - 1] & 0x80) == 0 {
+                        break;
                     }
-                    if (buf[5] & 0x80) != 0 {
-                        ENCODER
-                            .decode_mut(&bytes[8..12], &mut buf[6..9])
-                            .map_err(|_| Error::DecodeError)?;
+                    if bytes.len() < e + 4 {
+                        return Err(Error::DecodeError {
+                            offset: bytes.len(),
+                            kind: DecodeKind::Length,
+                        });
                     }
-                    Tag::new(&buf)
-                } else {
-                    return Err(Error::DecodeError);
+                    ENCODER
+                        .decode_mut(&bytes[e..e + 4], &mut buf[d..d + 3])
+                        .map_err(|p| Error::DecodeError {
+                            offset: e + p.error.position,
+                            kind: p.error.kind,
+                        })?;
                 }
+                Tag::new(&buf)
             }
         };
 
         Ok(tag)
     }
 
-    /// This takes a tag string name like "key.ed25519.public" and parses it
-    /// into a Tag containing the correct class, sub-class, and sub-sub-class
-    /// values. The length is initiatlized to zero.
-    fn decode_str(tag: &str, buf: &mut [u8]) -> Result<()> {
-        // checks if the value is experimental
-        fn experimental(v: u8) -> bool {
-            (v > 31) && (v != 63)
+    /// This takes a tag string name like "key.ed25519.public", or an alias
+    /// like "sha256", and parses it into a Tag containing the correct class,
+    /// sub-class, and sub-sub-class values. The length is initiatlized to zero.
+    fn decode_str(tag: &str, ignore_case: bool, buf: &mut [u8]) -> Result<()> {
+        // aliases have no dots so they never shadow a type string and always
+        // match in any case, the way JOSE and COSE spell them
+        let tag = alias(tag).unwrap_or(tag);
+
+        let mut segments = [""; 3];
+        let mut parts = 0;
+        for segment in tag.split('.') {
+            if parts == segments.len() {
+                return Err(Error::InvalidTypeName {
+                    name: tag.to_string(),
+                    reason: Reason::TooManyParts,
+                });
+            }
+            segments[parts] = segment;
+            parts += 1;
+        }
+        if segments[0].is_empty() {
+            // this case is triggered if the string is the empty string
+            return Err(class_err("", Reason::Missing));
+        }
+        if parts < 2 {
+            // there is no valid case where just the class is specified
+            return Err(subclass_err("", Reason::Missing));
         }
 
-        /// If the str is a single character
-        fn name_or_char(v: &str) -> Option<u8> {
-            if v.len() >= 1 {
-                if let Some(c) = v.chars().next() {
-                    if let Some(c) = CDE_ALPHABET.find(c) {
-                        return Some(c as u8);
-                    }
+        // run each step of the parser over its segment of the type string and
+        // then check the rules for that part, in order, before moving on
+        let mut p = Parsed {
+            ignore_case,
+            parts,
+            ..Default::default()
+        };
+        for (i, step) in STEPS.iter().enumerate() {
+            (step.resolve)(&mut p, segments[..parts].get(i).copied());
+            for rule in RULES.iter().filter(|r| r.part == step.part) {
+                if (rule.broken)(&p) {
+                    return Err(rule.part.error(p.segment(rule.part), rule.reason));
                 }
             }
-            None
         }
 
-        let parts = match tag.len() {
-            0 => (None, None, None),
-            _ => {
-                let mut s = tag.split('.');
-                (s.next(), s.next(), s.next())
-            }
-        };
+        let c = p.class.value.unwrap_or(0);
+        let sc = p.subclass.value.unwrap_or(0);
+        let ssc = p.subsubclass.and_then(|v| v.value).unwrap_or(0);
+        buf[0] = (((c & 0x3f) << 2) & 0xfc) | (((sc & 0x30) >> 4) & 0x03);
+        buf[1] = (((sc & 0x0f) << 4) & 0xf0) | (ssc & 0x0f);
+        buf[2] = 0;
 
-        let (c, sc, ssc) = match parts {
-            (Some(c_name), Some(sc_name), Some(ssc_name)) => {
-                match VALUES.get(c_name) {
-                    None => {
-                        if let Some(c) = name_or_char(c_name) {
-                            // if we get here they specified a non-standard...
-                            if !experimental(c) {
-                                // ...it must be experimental or it is an error...
-                                return Err(Error::InvalidClass);
-                            } else if let Some(sc) = name_or_char(sc_name) {
-                                if !experimental(sc) {
-                                    // ...the sub-class must be experimental or it is an error
-                                    return Err(Error::InvalidSubClass);
-                                } else if let Ok(ssc) = u8::from_str_radix(ssc_name, 10) {
-                                    // ...both class and sub-class are experimental so
-                                    // also return the sub-sub-class number
-                                    (c, sc, ssc)
-                                } else {
-                                    // ...the sub-sub-class was not a base 10 number
-                                    return Err(Error::InvalidSubSubClass);
-                                }
-                            } else {
-                                // ...the sub-class value wasn't a string
-                                return Err(Error::InvalidSubClass);
-                            }
-                        } else {
-                            // .. the class value wasn't a string
-                            return Err(Error::InvalidClass);
-                        }
-                    }
-                    Some((c, sc_map)) => {
-                        // the class name was a standard class name
-                        match sc_map.get(sc_name) {
-                            None => {
-                                // the sub-class was not a standard sub-class name
-                                if !experimental(*c) {
-                                    // ...the class must be experimental or it is an error
-                                    return Err(Error::InvalidClass);
-                                } else if let Some(sc) = name_or_char(sc_name) {
-                                    if !experimental(sc) {
-                                        // ...the sub-class must be experimental or it is an error
-                                        return Err(Error::InvalidSubClass);
-                                    } else if let Ok(ssc) = u8::from_str_radix(ssc_name, 10) {
-                                        // ...both class and sub-class are experimental so
-                                        // also return the sub-sub-class number
-                                        (*c, sc, ssc)
-                                    } else {
-                                        // ...the sub-sub-class was not a base 10 number
-                                        return Err(Error::InvalidSubSubClass);
-                                    }
-                                } else {
-                                    // ...the sub-class value wasn't a string
-                                    return Err(Error::InvalidSubClass);
-                                }
-                            }
-                            Some((sc, ssc_map)) => {
-                                // the sub-class name was a standard class name
-                                match ssc_map {
-                                    None => {
-                                        // there are no sub-sub-classes for this class and
-                                        // sub-class combination
-                                        if experimental(*c) {
-                                            if experimental(*sc) {
-                                                // both the class and sub-class are standard
-                                                // and experimental so just return them with
-                                                // the experimental sub-sub-class
-                                                if let Ok(ssc) = u8::from_str_radix(ssc_name, 10) {
-                                                    (*c, *sc, ssc)
-                                                } else {
-                                                    // the sub-sub-class was not a base 10 number
-                                                    return Err(Error::InvalidSubSubClass);
-                                                }
-                                            } else {
-                                                // an experimental class with a non-experimental
-                                                // sub-class is an error
-                                                return Err(Error::InvalidSubClass);
-                                            }
-                                        } else {
-                                            // this is a standard class and standard sub-class
-                                            // without any standard sub-sub-classes so the
-                                            // sub-class must be experimental
-                                            if !experimental(*sc) {
-                                                // there is a special corner case to take into
-                                                // account here... both "undefined" ('_') and list
-                                                // ('-') are not considered experimental but we
-                                                // allow list.list, undefined.list, and
-                                                // undefined.undefined to have sub-sub-classes set
-                                                // so that user can have different kinds of these
-                                                // types
-                                                if (*c == idx('_')
-                                                    && (*sc == idx('_') || *sc == idx('-')))
-                                                    || (*c == idx('-') && *sc == idx('-'))
-                                                {
-                                                    if let Ok(ssc) =
-                                                        u8::from_str_radix(ssc_name, 10)
-                                                    {
-                                                        (*c, *sc, ssc)
-                                                    } else {
-                                                        return Err(Error::InvalidSubSubClass);
-                                                    }
-                                                } else {
-                                                    return Err(Error::InvalidSubClass);
-                                                }
-                                            } else if let Ok(ssc) = u8::from_str_radix(ssc_name, 10)
-                                            {
-                                                // the sub-class is experimental so just get the
-                                                // sub-sub-class number and return all three
-                                                (*c, *sc, ssc)
-                                            } else {
-                                                // the sub-sub-class was not a base 10 number
-                                                return Err(Error::InvalidSubSubClass);
-                                            }
-                                        }
-                                    }
-                                    Some(ssc_map) => {
-                                        // there are sub-sub-classes for this class and
-                                        // sub-class combination
-                                        if experimental(*c) {
-                                            if experimental(*sc) {
-                                                // both the class and sub-class are standard
-                                                // and experimental so just return them with
-                                                // the experimental sub-sub-class
-                                                match ssc_map.get(ssc_name) {
-                                                    None => {
-                                                        if let Ok(ssc) =
-                                                            u8::from_str_radix(ssc_name, 10)
-                                                        {
-                                                            (*c, *sc, ssc)
-                                                        } else {
-                                                            return Err(Error::InvalidSubSubClass);
-                                                        }
-                                                    }
-                                                    // subtract 1 from the sub-sub-class number
-                                                    // because of a bug in the phf map we had to
-                                                    // use 1-indexed maps instead of 0-indexed maps
-                                                    Some(ssc) => (*c, *sc, *ssc - 1),
-                                                }
-                                            } else {
-                                                // an experimental class with a non-experimental
-                                                // sub-class is an error
-                                                return Err(Error::InvalidSubClass);
-                                            }
-                                        } else {
-                                            // the class is not experimental so it doesn't matter
-                                            // if the sub-class is experimental or not...just get
-                                            // the sub-sub-class number and return all three
-                                            match ssc_map.get(ssc_name) {
-                                                None => {
-                                                    if let Ok(ssc) =
-                                                        u8::from_str_radix(ssc_name, 10)
-                                                    {
-                                                        (*c, *sc, ssc)
-                                                    } else {
-                                                        return Err(Error::InvalidSubSubClass);
-                                                    }
-                                                }
-                                                // subtract 1 from the sub-sub-class number
-                                                // because of a bug in the phf map we had to
-                                                // use 1-indexed maps instead of 0-indexed maps
-                                                Some(ssc) => (*c, *sc, *ssc - 1),
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+        Ok(())
+    }
+}
 
-            (Some(c_name), Some(sc_name), None) => {
-                match VALUES.get(c_name) {
-                    None => {
-                        // the class is non-standard...
-                        if let Some(c) = name_or_char(c_name) {
-                            if !experimental(c) {
-                                // ...it must be experimental or it is an error
-                                return Err(Error::InvalidClass);
-                            } else if let Some(sc) = name_or_char(sc_name) {
-                                if !experimental(sc) {
-                                    // ...and therefore the sub-class must also be
-                                    // experimental or it is an error
-                                    return Err(Error::InvalidSubClass);
-                                } else {
-                                    // it is OK to specify an experimental non-standard class and
-                                    // experimental non-standard sub-class without a sub-sub-class.
-                                    // the sub-sub-class just defaults to 0
-                                    (c, sc, 0)
-                                }
-                            } else {
-                                // the sub-class name wasn't a string
-                                return Err(Error::InvalidSubClass);
-                            }
-                        } else {
-                            // the class name wasn't a string
-                            return Err(Error::InvalidClass);
-                        }
-                    }
-                    Some((c, sc_map)) => {
-                        // the class is standard...
-                        match sc_map.get(sc_name) {
-                            None => {
-                                // ...the sub-class name is non-standard...
-                                if !experimental(*c) {
-                                    // ...the class must be experimental or it is an error...
-                                    return Err(Error::InvalidClass);
-                                } else if let Some(sc) = name_or_char(sc_name) {
-                                    if !experimental(sc) {
-                                        // ...the sub-class must also be experimental
-                                        // or it is an error
-                                        return Err(Error::InvalidSubClass);
-                                    } else {
-                                        // it is OK to specify an experimental standard class and
-                                        // an experimental non-standard sub-class without
-                                        // specifying a sub-sub-class. the sub-sub-class just
-                                        // defaults to 0
-                                        (*c, sc, 0)
-                                    }
-                                } else {
-                                    // ...the sub-class name wasn't a string
-                                    return Err(Error::InvalidSubClass);
-                                }
-                            }
-                            Some((sc, ssc_map)) => {
-                                // ...the sub-class name is standard...
-                                match ssc_map {
-                                    None => {
-                                        // standard class and standard sub-class without any
-                                        // standard sub-sub-classes are allowed
-                                        (*c, *sc, 0)
-                                    }
-                                    Some(_) => {
-                                        // if we get here, there is a sub-sub-class
-                                        // map and they didn't specify which sub-sub-class
-                                        // this is an error
-                                        return Err(Error::InvalidSubSubClass);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+/// Returns the canonical dotted type name for an alias like "sha256" or
+/// "ed25519-pub", ignoring case
+pub fn alias(name: &str) -> Option<&'static str> {
+    ALIASES.get(name.to_ascii_lowercase().as_str()).copied()
+}
+
+/// Returns an iterator over all of the (alias, canonical name) pairs
+pub fn aliases() -> impl Iterator<Item = (&'static str, &'static str)> {
+    ALIASES.entries().map(|(a, n)| (*a, *n))
+}
+
+/// The '.' separated parts of a type string in the order they are parsed
+#[derive(Clone, Copy, PartialEq)]
+enum Part {
+    Class,
+    SubClass,
+    SubSubClass,
+}
+
+impl Part {
+    fn error(self, segment: &str, reason: Reason) -> Error {
+        match self {
+            Part::Class => class_err(segment, reason),
+            Part::SubClass => subclass_err(segment, reason),
+            Part::SubSubClass => subsubclass_err(segment, reason),
+        }
+    }
+}
+
+/// A part of a type string and the value it resolved to, if any
+#[derive(Clone, Copy, Default)]
+struct Value<'a> {
+    segment: &'a str,
+    value: Option<u8>,
+    standard: bool,
+}
+
+impl<'a> Value<'a> {
+    fn experimental(&self) -> bool {
+        matches!(self.value, Some(v) if (v > 31) && (v != 63))
+    }
+}
+
+/// The state of a type string parse
+#[derive(Default)]
+struct Parsed<'a> {
+    ignore_case: bool,
+    // the number of '.' separated parts in the type string
+    parts: usize,
+    class: Value<'a>,
+    subclass: Value<'a>,
+    subsubclass: Option<Value<'a>>,
+    // the standard sub-classes of a standard class
+    subclasses: Option<&'static SubValuesMap<'static>>,
+    // the standard sub-sub-classes of a standard sub-class
+    subsubclasses: Option<&'static SubSubValuesMap>,
+}
+
+impl<'a> Parsed<'a> {
+    fn segment(&self, part: Part) -> &'a str {
+        match part {
+            Part::Class => self.class.segment,
+            Part::SubClass => self.subclass.segment,
+            Part::SubSubClass => self.subsubclass.map(|v| v.segment).unwrap_or(""),
+        }
+    }
+}
 
-            (Some(_), None, None) => {
-                // there is no valid case where just the class is specified
-                return Err(Error::InvalidSubClass);
+/// A parser state that resolves the segment for one part of a type string
+struct Step {
+    part: Part,
+    resolve: for<'a> fn(&mut Parsed<'a>, Option<&'a str>),
+}
+
+static STEPS: [Step; 3] = [
+    Step {
+        part: Part::Class,
+        resolve: resolve_class,
+    },
+    Step {
+        part: Part::SubClass,
+        resolve: resolve_subclass,
+    },
+    Step {
+        part: Part::SubSubClass,
+        resolve: resolve_subsubclass,
+    },
+];
+
+/// A rule of the type string grammar. The rules for a part are checked in
+/// order as soon as it has been resolved and the first broken rule is the
+/// error. New rules from the spec go here.
+struct Rule {
+    part: Part,
+    reason: Reason,
+    broken: fn(&Parsed) -> bool,
+}
+
+static RULES: [Rule; 11] = [
+    // a non-standard class must begin with a letter from the alphabet...
+    Rule {
+        part: Part::Class,
+        reason: Reason::InvalidFirstLetter,
+        broken: |p| p.class.value.is_none(),
+    },
+    // ...and be experimental
+    Rule {
+        part: Part::Class,
+        reason: Reason::NotExperimental,
+        broken: |p| !p.class.standard && !p.class.experimental(),
+    },
+    // a non-standard sub-class is only allowed in an experimental class...
+    Rule {
+        part: Part::SubClass,
+        reason: Reason::ParentNotExperimental,
+        broken: |p| !p.subclass.standard && p.class.standard && !p.class.experimental(),
+    },
+    // ...it can't be empty...
+    Rule {
+        part: Part::SubClass,
+        reason: Reason::Missing,
+        broken: |p| p.subclass.segment.is_empty(),
+    },
+    // ...it must begin with a letter from the alphabet...
+    Rule {
+        part: Part::SubClass,
+        reason: Reason::InvalidFirstLetter,
+        broken: |p| p.subclass.value.is_none(),
+    },
+    // ...and it must be experimental itself
+    Rule {
+        part: Part::SubClass,
+        reason: Reason::NotExperimental,
+        broken: |p| !p.subclass.standard && !p.subclass.experimental(),
+    },
+    // an experimental class with a non-experimental sub-class is an error
+    // when a sub-sub-class is given, the two part names are allowed
+    Rule {
+        part: Part::SubClass,
+        reason: Reason::ParentExperimental,
+        broken: |p| p.parts > 2 && p.class.experimental() && !p.subclass.experimental(),
+    },
+    // a sub-class with standard sub-sub-classes must say which one
+    Rule {
+        part: Part::SubSubClass,
+        reason: Reason::Missing,
+        broken: |p| p.subsubclasses.is_some() && p.subsubclass.is_none(),
+    },
+    // a standard sub-class without standard sub-sub-classes only takes a
+    // numbered sub-sub-class if it is experimental or is one of the numbered
+    // undefined/list types
+    Rule {
+        part: Part::SubSubClass,
+        reason: Reason::NotAllowed,
+        broken: |p| {
+            p.subsubclass.is_some()
+                && p.subsubclasses.is_none()
+                && p.subclass.standard
+                && !p.subclass.experimental()
+                && !NUMBERED.iter().any(|(c, sc)| {
+                    p.class.value == Some(idx(*c)) && p.subclass.value == Some(idx(*sc))
+                })
+        },
+    },
+    // a non-standard sub-sub-class must be a base 10 number...
+    Rule {
+        part: Part::SubSubClass,
+        reason: Reason::NotANumber,
+        broken: |p| matches!(p.subsubclass, Some(v) if v.value.is_none()),
+    },
+    // ...that fits in four bits
+    Rule {
+        part: Part::SubSubClass,
+        reason: Reason::OutOfRange,
+        broken: |p| matches!(p.subsubclass, Some(Value { value: Some(v), .. }) if v > 0x0f),
+    },
+];
+
+/// Both "undefined" ('_') and list ('-') are not considered experimental but
+/// we allow list.list, undefined.list, and undefined.undefined to have
+/// sub-sub-classes set so that user can have different kinds of these types
+static NUMBERED: [(char, char); 3] = [('_', '_'), ('_', '-'), ('-', '-')];
+
+/// Looks up a standard name, falling back to lower case if ignoring case
+fn lookup<'m, V>(
+    map: &'m phf::OrderedMap<&'static str, V>,
+    segment: &str,
+    ignore_case: bool,
+) -> Option<&'m V> {
+    match map.get(segment) {
+        None if ignore_case => map.get(segment.to_ascii_lowercase().as_str()),
+        v => v,
+    }
+}
+
+/// Resolves a non-standard class or sub-class name using its first letter
+fn first_letter(segment: &str) -> Value<'_> {
+    Value {
+        segment,
+        value: segment
+            .chars()
+            .next()
+            .and_then(|c| CDE_ALPHABET.find(c))
+            .map(|v| v as u8),
+        standard: false,
+    }
+}
+
+fn resolve_class<'a>(p: &mut Parsed<'a>, segment: Option<&'a str>) {
+    let segment = segment.unwrap_or("");
+    p.class = match lookup(&VALUES, segment, p.ignore_case) {
+        Some((value, subclasses)) => {
+            p.subclasses = Some(*subclasses);
+            Value {
+                segment,
+                value: Some(*value),
+                standard: true,
             }
+        }
+        None => first_letter(segment),
+    };
+}
 
-            (None, None, None) => {
-                // there is no valid case where nothing is specified...
-                // this case is triggered if the string is the empty string
-                return Err(Error::InvalidClass);
+fn resolve_subclass<'a>(p: &mut Parsed<'a>, segment: Option<&'a str>) {
+    let segment = segment.unwrap_or("");
+    let standard = p
+        .subclasses
+        .and_then(|subclasses| lookup(subclasses, segment, p.ignore_case));
+    p.subclass = match standard {
+        Some((value, subsubclasses)) => {
+            p.subsubclasses = *subsubclasses;
+            Value {
+                segment,
+                value: Some(*value),
+                standard: true,
             }
+        }
+        None => first_letter(segment),
+    };
+}
 
-            // everything other combination is invalid...
-            _ => return Err(Error::FromStr),
-        };
+fn resolve_subsubclass<'a>(p: &mut Parsed<'a>, segment: Option<&'a str>) {
+    let segment = match segment {
+        Some(segment) => segment,
+        None => return,
+    };
+    let standard = p
+        .subsubclasses
+        .and_then(|subsubclasses| lookup(subsubclasses, segment, p.ignore_case));
+    p.subsubclass = Some(match standard {
+        // subtract 1 from the sub-sub-class number because of a bug in the phf
+        // map we had to use 1-indexed maps instead of 0-indexed maps
+        Some(value) => Value {
+            segment,
+            value: Some(*value - 1),
+            standard: true,
+        },
+        // anything else must be a number, the rules check that it is valid
+        None => Value {
+            segment,
+            value: segment.parse::<u8>().ok(),
+            standard: false,
+        },
+    });
+}
 
-        buf[0] = (((c & 0x3f) << 2) & 0xfc) | (((sc & 0x30) >> 4) & 0x03);
-        buf[1] = (((sc & 0x0f) << 4) & 0xf0) | (ssc & 0x07);
-        buf[2] = 0;
+fn class_err(segment: &str, reason: Reason) -> Error {
+    Error::InvalidClass {
+        segment: segment.to_string(),
+        reason,
+    }
+}
 
-        Ok(())
+fn subclass_err(segment: &str, reason: Reason) -> Error {
+    Error::InvalidSubClass {
+        segment: segment.to_string(),
+        reason,
+    }
+}
+
+fn subsubclass_err(segment: &str, reason: Reason) -> Error {
+    Error::InvalidSubSubClass {
+        segment: segment.to_string(),
+        reason,
+    }
+}
+
+impl FromStr for Tag {
+    type Err = Error;
+
+    /// Parses a type string such as "key.ed25519.public"
+    fn from_str(s: &str) -> Result<Self> {
+        TagBuilder::from_tag(s).build()
+    }
+}
+
+impl TryFrom<&str> for Tag {
+    type Error = Error;
+
+    /// Parses a type string such as "key.ed25519.public"
+    fn try_from(s: &str) -> Result<Self> {
+        TagBuilder::from_tag(s).build()
+    }
+}
+
+impl TryFrom<&[u8]> for Tag {
+    type Error = Error;
+
+    /// Parses a tag from the start of its binary encoding
+    fn try_from(b: &[u8]) -> Result<Self> {
+        TagBuilder::from_bytes(b).build()
     }
 }
 
@@ -530,7 +804,7 @@ impl Debug for Tag {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         let mut b = [0u8; 12];
         self.encode(&mut b);
-        let s = core::str::from_utf8(&mut b).unwrap();
+        let s = core::str::from_utf8(&b).unwrap();
         let mut i = s.chars();
         let (cn, scn, sscn) = self.name().unwrap();
         let sscn = match sscn {
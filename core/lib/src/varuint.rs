@@ -1,17 +1,21 @@
 use crate::{CryptoData, Error, ENCODER};
 use core::{
-    convert::{From, Into},
+    convert::From,
     fmt,
     ops::{Deref, DerefMut},
 };
 
-#[derive(Clone, Copy, Default, PartialEq)]
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct VarUInt(u64);
 
+/// The most bytes a VarUInt is read from, nine bytes of seven bits fill all but
+/// the top bit of a u64 and stop longer runs from shifting past it
+const MAX_BYTES: usize = 9;
+
 impl<'a> From<&'a [u8]> for VarUInt {
     fn from(buf: &'a [u8]) -> VarUInt {
         let mut v = 0u64;
-        for (i, b) in buf.iter().cloned().enumerate() {
+        for (i, b) in buf.iter().take(MAX_BYTES).cloned().enumerate() {
             let k = u64::from(b & 0x7f);
             v |= k << (i * 7);
             if b & 0x80 == 0 {
@@ -26,7 +30,7 @@ impl VarUInt {
     pub fn try_parse(buf: &[u8]) -> Result<Self, Error> {
         let mut complete = false;
         let mut v = 0u64;
-        for (i, b) in buf.iter().cloned().enumerate() {
+        for (i, b) in buf.iter().take(MAX_BYTES).cloned().enumerate() {
             let k = u64::from(b & 0x7f);
             v |= k << (i * 7);
             if b & 0x80 == 0 {
@@ -36,9 +40,9 @@ impl VarUInt {
         }
 
         if complete {
-            return Ok(VarUInt(v));
+            Ok(VarUInt(v))
         } else {
-            return Err(Error::InvalidLength);
+            Err(Error::InvalidLength)
         }
     }
 }
@@ -49,9 +53,9 @@ impl From<u64> for VarUInt {
     }
 }
 
-impl Into<u64> for VarUInt {
-    fn into(self) -> u64 {
-        self.0
+impl From<VarUInt> for u64 {
+    fn from(v: VarUInt) -> u64 {
+        v.0
     }
 }
 
@@ -61,9 +65,9 @@ impl From<usize> for VarUInt {
     }
 }
 
-impl Into<usize> for VarUInt {
-    fn into(self) -> usize {
-        self.0 as usize
+impl From<VarUInt> for usize {
+    fn from(v: VarUInt) -> usize {
+        v.0 as usize
     }
 }
 
@@ -91,8 +95,8 @@ impl CryptoData for VarUInt {
     fn len(&self) -> usize {
         match self.0 {
             n if n < 128 => 1,
-            n if n >= 128 && n < 268_435_456 => 4,
-            n if n >= 268_435_456 && n < 562_949_953_421_311 => 7,
+            n if (128..268_435_456).contains(&n) => 4,
+            n if (268_435_456..562_949_953_421_311).contains(&n) => 7,
             _ => 7,
         }
     }
@@ -0,0 +1,44 @@
+//! Conversions between CDE x25519 keys and the x25519-dalek key types
+//!
+//! A `key.x25519.secret` object is a `StaticSecret` and a `key.x25519.public`
+//! object is a `PublicKey`.
+use crate::{
+    key::{self, Algorithm, Half},
+    Error, Object, Result,
+};
+use std::convert::TryFrom;
+use x25519_dalek::{PublicKey, StaticSecret};
+
+impl TryFrom<&Object> for StaticSecret {
+    type Error = Error;
+
+    /// Creates a static secret from a `key.x25519.secret` object
+    fn try_from(obj: &Object) -> Result<Self> {
+        key::expect(obj, Algorithm::X25519, Half::Secret)?;
+        Ok(StaticSecret::from(key::bytes(obj)?))
+    }
+}
+
+impl TryFrom<&Object> for PublicKey {
+    type Error = Error;
+
+    /// Creates a public key from a `key.x25519.public` object
+    fn try_from(obj: &Object) -> Result<Self> {
+        key::expect(obj, Algorithm::X25519, Half::Public)?;
+        Ok(PublicKey::from(key::bytes(obj)?))
+    }
+}
+
+impl From<&StaticSecret> for Object {
+    /// Creates a `key.x25519.secret` object from a static secret
+    fn from(key: &StaticSecret) -> Self {
+        Object::new(Algorithm::X25519.tag(Half::Secret), key.as_bytes())
+    }
+}
+
+impl From<&PublicKey> for Object {
+    /// Creates a `key.x25519.public` object from a public key
+    fn from(key: &PublicKey) -> Self {
+        Object::new(Algorithm::X25519.tag(Half::Public), key.as_bytes())
+    }
+}
@@ -0,0 +1,150 @@
+//! X.509 certificates as `signature.x509` objects
+//!
+//! The data of a `signature.x509` object is the DER encoded certificate. The
+//! certificate is parsed when the object is created so a `signature.x509`
+//! object made here always holds a well formed certificate. The subject public
+//! key is converted to a CDE key with the `der` feature so only the key
+//! algorithms supported there can be extracted.
+use crate::{der, Error, Object, Result};
+use core::fmt::{self, Display, Formatter};
+use data_encoding::BASE64;
+use std::time::SystemTime;
+use x509_cert::{
+    der::{Decode, Encode},
+    Certificate,
+};
+
+const BEGIN: &str = "-----BEGIN CERTIFICATE-----";
+const END: &str = "-----END CERTIFICATE-----";
+
+/// The number of characters in each line of the PEM body
+const WIDTH: usize = 64;
+
+/// A parsed X.509 certificate
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct X509Certificate {
+    der: Vec<u8>,
+    cert: Certificate,
+}
+
+impl X509Certificate {
+    /// Parses a DER encoded certificate
+    pub fn from_der(b: &[u8]) -> Result<Self> {
+        let cert = Certificate::from_der(b).map_err(x509_err)?;
+        Ok(X509Certificate {
+            der: b.to_vec(),
+            cert,
+        })
+    }
+
+    /// Parses the first "CERTIFICATE" block in PEM text
+    pub fn from_pem(s: &str) -> Result<Self> {
+        let mut lines = s.lines().map(str::trim).skip_while(|l| *l != BEGIN).skip(1);
+        let mut text = String::new();
+        loop {
+            match lines.next() {
+                None => return Err(x509_err("missing PEM END line")),
+                Some(END) => break,
+                Some(l) => text.push_str(l),
+            }
+        }
+        Self::from_der(&BASE64.decode(text.as_bytes())?)
+    }
+
+    /// Parses a certificate that is either PEM text or DER
+    pub fn parse(b: &[u8]) -> Result<Self> {
+        match core::str::from_utf8(b) {
+            Ok(s) if s.contains(BEGIN) => Self::from_pem(s),
+            _ => Self::from_der(b),
+        }
+    }
+
+    /// Parses the certificate in a `signature.x509` object
+    pub fn from_object(obj: &Object) -> Result<Self> {
+        if obj.tag().canonical_name() != "signature.x509" {
+            return Err(Error::UnsupportedType(obj.tag().canonical_name()));
+        }
+        Self::from_der(obj.data())
+    }
+
+    /// Returns the certificate as a `signature.x509` object
+    pub fn to_object(&self) -> Result<Object> {
+        Object::from_type("signature.x509", &self.der)
+    }
+
+    /// Returns the DER encoded certificate
+    pub fn der(&self) -> &[u8] {
+        &self.der
+    }
+
+    /// Returns the certificate as PEM text
+    pub fn to_pem(&self) -> String {
+        let mut s = format!("{}\n", BEGIN);
+        for line in BASE64.encode(&self.der).as_bytes().chunks(WIDTH) {
+            // base64 is all ascii so the chunks are always valid utf-8
+            s.push_str(core::str::from_utf8(line).unwrap_or_default());
+            s.push('\n');
+        }
+        s.push_str(&format!("{}\n", END));
+        s
+    }
+
+    /// Returns the subject name as an RFC 4514 string
+    pub fn subject(&self) -> String {
+        self.cert.tbs_certificate.subject.to_string()
+    }
+
+    /// Returns the issuer name as an RFC 4514 string
+    pub fn issuer(&self) -> String {
+        self.cert.tbs_certificate.issuer.to_string()
+    }
+
+    /// Returns the start of the validity period
+    pub fn not_before(&self) -> SystemTime {
+        self.cert
+            .tbs_certificate
+            .validity
+            .not_before
+            .to_system_time()
+    }
+
+    /// Returns the end of the validity period
+    pub fn not_after(&self) -> SystemTime {
+        self.cert
+            .tbs_certificate
+            .validity
+            .not_after
+            .to_system_time()
+    }
+
+    /// Returns the subject public key as a CDE public key
+    pub fn public_key(&self) -> Result<Object> {
+        let spki = self
+            .cert
+            .tbs_certificate
+            .subject_public_key_info
+            .to_der()
+            .map_err(x509_err)?;
+        der::from_spki(&spki)
+    }
+}
+
+impl Display for X509Certificate {
+    /// Writes the subject, issuer, validity and CDE public key, one per line
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let validity = &self.cert.tbs_certificate.validity;
+        let key = match self.public_key() {
+            Ok(key) => key.to_string(),
+            Err(e) => e.to_string(),
+        };
+        writeln!(f, "subject:    {}", self.subject())?;
+        writeln!(f, "issuer:     {}", self.issuer())?;
+        writeln!(f, "not before: {}", validity.not_before)?;
+        writeln!(f, "not after:  {}", validity.not_after)?;
+        writeln!(f, "public key: {}", key)
+    }
+}
+
+fn x509_err<E: Display>(e: E) -> Error {
+    Error::InvalidCertificate(e.to_string())
+}
@@ -0,0 +1,203 @@
+#![cfg(any(feature = "aes-gcm", feature = "chacha20poly1305"))]
+
+mod common;
+
+mod aead {
+    use crate::common::hex;
+    use cde::{aead, Error, Object, Tag, TagBuilder};
+    use std::convert::TryFrom;
+
+    fn key(name: &str, len: usize) -> Object {
+        let data: Vec<u8> = (0..len as u8).collect();
+        Object::from_type(name, &data).unwrap()
+    }
+
+    fn tag(name: &str) -> Tag {
+        TagBuilder::from_tag(name).build().unwrap()
+    }
+
+    #[cfg(feature = "aes-gcm")]
+    #[test]
+    fn aes_gcm() {
+        use aes_gcm::{
+            aead::{Aead, KeyInit},
+            Aes128Gcm, Aes256Gcm, Nonce,
+        };
+
+        let nonce = &Nonce::default();
+        let cipher = Aes128Gcm::try_from(&key("key.aes.128", 16)).unwrap();
+        let expected = Aes128Gcm::new_from_slice(&(0..16).collect::<Vec<u8>>()).unwrap();
+        assert_eq!(
+            expected.encrypt(nonce, &b"cde"[..]).unwrap(),
+            cipher.encrypt(nonce, &b"cde"[..]).unwrap()
+        );
+        assert!(Aes256Gcm::try_from(&key("key.aes.256", 32)).is_ok());
+
+        assert!(matches!(
+            Aes128Gcm::try_from(&key("key.aes.256", 32)),
+            Err(Error::UnsupportedType(_))
+        ));
+        assert!(matches!(
+            Aes256Gcm::try_from(&key("key.aes.256", 16)),
+            Err(Error::InvalidKey { .. })
+        ));
+    }
+
+    #[cfg(feature = "chacha20poly1305")]
+    #[test]
+    fn chacha20poly1305() {
+        use chacha20poly1305::{ChaCha20Poly1305, XChaCha20Poly1305};
+
+        assert!(ChaCha20Poly1305::try_from(&key("key.chacha20", 32)).is_ok());
+        assert!(XChaCha20Poly1305::try_from(&key("key.chacha20", 32)).is_ok());
+        assert!(matches!(
+            ChaCha20Poly1305::try_from(&key("key.chacha20", 31)),
+            Err(Error::InvalidKey { .. })
+        ));
+        assert!(matches!(
+            XChaCha20Poly1305::try_from(&key("key.aes.256", 32)),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+
+    #[cfg(feature = "aes-gcm")]
+    #[test]
+    fn seal_aes256_gcm() {
+        let key = key("key.aes.256", 32);
+        let env =
+            aead::seal_with_nonce(&key, tag("aead.aes256-gcm"), &[0; 12], b"cde", b"ad").unwrap();
+        assert_eq!("aead.aes256-gcm", env.algorithm().canonical_name());
+        assert_eq!(
+            hex("6dd8d0acfadbe5f2b46b4affa66ffb25f1c908"),
+            env.ciphertext()
+        );
+        assert_eq!(b"cde".to_vec(), aead::open(&key, &env).unwrap());
+
+        // a 128 bit key can't be used with aes256-gcm
+        assert!(matches!(
+            aead::seal(
+                &self::key("key.aes.128", 16),
+                tag("aead.aes256-gcm"),
+                b"",
+                b""
+            ),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+
+    #[cfg(feature = "chacha20poly1305")]
+    #[test]
+    fn seal_chacha20_poly1305() {
+        // the AEAD test vector from RFC 8439 section 2.8.2
+        let key = Object::from_type("key.chacha20", &(0x80..0xa0).collect::<Vec<u8>>()).unwrap();
+        let msg = &b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it."[..];
+        let env = aead::seal_with_nonce(
+            &key,
+            tag("aead.chacha20-poly1305-ietf"),
+            &hex("070000004041424344454647"),
+            msg,
+            &hex("50515253c0c1c2c3c4c5c6c7"),
+        )
+        .unwrap();
+        assert_eq!(
+            hex(concat!(
+                "d31a8d34648e60db7b86afbc53ef7ec2a4aded51296e08fea9e2b5a736ee62d6",
+                "3dbea45e8ca9671282fafb69da92728b1a71de0a9e060b2905d6a5b67ecd3b36",
+                "92ddbd7f2d778b8c9803aee328091b58fab324e4fad675945585808b4831d7bc",
+                "3ff4def08e4b7a9de576d26586cec64b61161ae10b594f09e26a7e902ecbd060",
+                "0691"
+            )),
+            env.ciphertext()
+        );
+        assert_eq!(msg.to_vec(), aead::open(&key, &env).unwrap());
+    }
+
+    #[cfg(feature = "chacha20poly1305")]
+    #[test]
+    fn envelope() {
+        let key = key("key.chacha20", 32);
+        let env = aead::seal(&key, tag("aead.xchacha20-poly1305-ietf"), b"cde", b"ad").unwrap();
+        assert_eq!(24, env.nonce().len());
+        assert_eq!(b"ad", env.associated_data());
+
+        let obj = env.to_object().unwrap();
+        assert_eq!("aead.list", obj.tag().canonical_name());
+        let items = obj.items().unwrap();
+        assert_eq!("nonce.bytes", items[0].tag().canonical_name());
+        assert_eq!("undefined.undefined", items[1].tag().canonical_name());
+        assert_eq!(
+            "aead.xchacha20-poly1305-ietf",
+            items[2].tag().canonical_name()
+        );
+
+        let obj = Object::from_encoded(obj.to_string().as_bytes()).unwrap();
+        let opened = aead::Envelope::from_object(&obj).unwrap();
+        assert_eq!(env, opened);
+        assert_eq!(b"cde".to_vec(), aead::open(&key, &opened).unwrap());
+    }
+
+    #[cfg(feature = "chacha20poly1305")]
+    #[test]
+    fn tampered() {
+        let key = key("key.chacha20", 32);
+        let alg = tag("aead.chacha20-poly1305-ietf");
+        let env = aead::seal(&key, alg, b"cde", b"ad").unwrap();
+
+        // changing the associated data fails to authenticate
+        let other = aead::seal_with_nonce(&key, alg, env.nonce(), b"cde", b"da").unwrap();
+        let mut items = env.to_object().unwrap().items().unwrap();
+        items[1] = other.to_object().unwrap().items().unwrap()[1].clone();
+        let obj = Object::list(tag("aead.list"), &items);
+        let env = aead::Envelope::from_object(&obj).unwrap();
+        assert!(matches!(
+            aead::open(&key, &env),
+            Err(Error::DecryptionFailed)
+        ));
+
+        // so does the wrong key
+        let env = aead::seal(&key, alg, b"cde", b"").unwrap();
+        let mut other = vec![0; 32];
+        other[0] = 1;
+        let other = Object::from_type("key.chacha20", &other).unwrap();
+        assert!(matches!(
+            aead::open(&other, &env),
+            Err(Error::DecryptionFailed)
+        ));
+    }
+
+    #[cfg(feature = "chacha20poly1305")]
+    #[test]
+    fn invalid() {
+        let key = key("key.chacha20", 32);
+        assert!(matches!(
+            aead::seal_with_nonce(
+                &key,
+                tag("aead.xchacha20-poly1305-ietf"),
+                &[0; 12],
+                b"",
+                b""
+            ),
+            Err(Error::AeadError(_))
+        ));
+        assert!(matches!(
+            aead::seal(&key, tag("aead.chacha20-poly1305"), b"", b""),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+        assert!(matches!(
+            aead::seal(&key, tag("key.chacha20"), b"", b""),
+            Err(Error::UnsupportedType(_))
+        ));
+
+        // an envelope needs all three items
+        let nonce = Object::from_type("nonce.bytes", &[0; 12]).unwrap();
+        let obj = Object::list(tag("aead.list"), &[nonce]);
+        assert!(matches!(
+            aead::Envelope::from_object(&obj),
+            Err(Error::AeadError(_))
+        ));
+        assert!(matches!(
+            aead::Envelope::from_object(&key),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+}
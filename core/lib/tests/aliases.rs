@@ -0,0 +1,151 @@
+mod aliases {
+    use cde::{alias, aliases, idx, Error, Reason, Tag, TagBuilder};
+    use std::convert::TryFrom;
+
+    fn tag(s: &str) -> Tag {
+        TagBuilder::from_tag(s).build().unwrap()
+    }
+
+    #[test]
+    fn sha256() {
+        let tt = tag("sha256");
+        assert_eq!(idx('d'), tt.class());
+        assert_eq!(idx('h'), tt.subclass());
+        assert_eq!(0, tt.subsubclass());
+        assert_eq!(tag("digest.sha2.256"), tt);
+    }
+
+    #[test]
+    fn digests() {
+        assert_eq!(tag("digest.sha1"), tag("sha1"));
+        assert_eq!(tag("digest.sha2.512/256"), tag("sha512/256"));
+        assert_eq!(tag("digest.sha3.384"), tag("sha3-384"));
+        assert_eq!(tag("digest.sha3.shake256"), tag("shake256"));
+        assert_eq!(tag("digest.blake2.s"), tag("blake2s"));
+        assert_eq!(tag("digest.md.5"), tag("md5"));
+    }
+
+    #[test]
+    fn hmacs() {
+        assert_eq!(tag("hmac.sha2.256"), tag("hmac-sha256"));
+        assert_eq!(tag("hmac.sha2.512"), tag("HS512"));
+        assert_eq!(tag("hmac.sha3.384"), tag("hmac-sha3-384"));
+        assert_eq!(tag("hmac.blake2.b"), tag("hmac-blake2b"));
+    }
+
+    #[test]
+    fn alias_ignores_case() {
+        assert_eq!(tag("digest.sha2.256"), tag("SHA256"));
+        assert_eq!(tag("digest.sha3.224"), tag("Sha3-224"));
+        let tt = TagBuilder::from_tag("SHA256")
+            .ignore_case(true)
+            .build()
+            .unwrap();
+        assert_eq!(tag("digest.sha2.256"), tt);
+    }
+
+    #[test]
+    fn ignore_case() {
+        let tt = TagBuilder::from_tag("KEY.ED25519.PUBLIC")
+            .ignore_case(true)
+            .build()
+            .unwrap();
+        assert_eq!(tag("key.ed25519.public"), tt);
+    }
+
+    #[test]
+    fn ignore_case_prefers_exact_match() {
+        // "Key" is the experimental twin of "key"
+        let tt = TagBuilder::from_tag("Key.Ed25519.public")
+            .ignore_case(true)
+            .build()
+            .unwrap();
+        assert_eq!(tag("Key.Ed25519.public"), tt);
+        assert!(tt.is_exp_class());
+    }
+
+    #[test]
+    fn case_sensitive_by_default() {
+        // without ignoring case these are experimental names
+        let tt = tag("KEY.ED25519");
+        assert!(tt.is_exp_class());
+        assert!(tt.is_exp_sub_class());
+        assert_eq!(idx('K'), tt.class());
+        assert_eq!(idx('E'), tt.subclass());
+        match TagBuilder::from_tag("KEY.ED25519.PUBLIC")
+            .build()
+            .unwrap_err()
+        {
+            Error::InvalidSubSubClass { segment, reason } => {
+                assert_eq!("PUBLIC", segment);
+                assert_eq!(Reason::NotANumber, reason);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn keys() {
+        assert_eq!(tag("key.ed25519.public"), tag("ed25519-pub"));
+        assert_eq!(tag("key.ed25519.secret"), tag("ed25519-priv"));
+        assert_eq!(tag("key.x25519.secret"), tag("x25519-secret"));
+        assert_eq!(tag("key.k256.public"), tag("secp256k1-pub"));
+        assert_eq!(tag("key.aes.256"), tag("aes-256"));
+    }
+
+    #[test]
+    fn jose_and_cose_names() {
+        assert_eq!(tag("key.p256.public"), tag("P-256-pub"));
+        assert_eq!(tag("aead.aes256-gcm"), tag("A256GCM"));
+        assert_eq!(tag("aead.aes256-gcm"), tag("aes-256-gcm"));
+        assert_eq!(tag("aead.chacha20-poly1305-ietf"), tag("ChaCha20/Poly1305"));
+        assert_eq!(tag("digest.sha2.256"), tag("SHA-256"));
+        assert_eq!(tag("digest.sha2.512/256"), tag("SHA-512/256"));
+        assert_eq!(tag("hmac.sha2.256"), "HS256".parse::<Tag>().unwrap());
+        assert_eq!(tag("aead.aes256-gcm"), Tag::try_from("A256GCM").unwrap());
+        assert_eq!(tag("signature.Ecdsa.p256"), tag("ES256"));
+        assert_eq!(tag("signature.Ecdsa.k256"), tag("ES256K"));
+        assert_eq!(tag("signature.Ed25519"), tag("EdDSA"));
+    }
+
+    #[test]
+    fn lookup() {
+        assert_eq!(Some("digest.sha2.256"), alias("sha256"));
+        assert_eq!(Some("key.ed25519.public"), alias("Ed25519-Pub"));
+        assert_eq!(None, alias("key.ed25519.public"));
+    }
+
+    #[test]
+    fn canonical_names() {
+        // every alias maps to a canonical name
+        for (a, name) in aliases() {
+            let tt = tag(a);
+            assert_eq!(name, tt.canonical_name());
+            assert_eq!(tt, tag(&tt.canonical_name()));
+        }
+    }
+
+    #[test]
+    fn canonical_name_round_trip() {
+        for s in &[
+            "claim.oberon",
+            "claim.Oberon",
+            "Claim.Oberon",
+            "list.list",
+            "list.list.3",
+            "undefined.undefined.15",
+            "key.ed25519.public",
+            "Key.Ed25519.secret",
+            "Foo.Bar",
+            "Foo.Bar.7",
+            "Key.Xyz.2",
+        ] {
+            let tt = tag(s);
+            assert_eq!(tt, tag(&tt.canonical_name()));
+        }
+        assert_eq!("claim.oberon", tag("claim.oberon").canonical_name());
+        assert_eq!("9.B.7", tag("9x.Bar.7").canonical_name());
+        assert_eq!("Strobe.B.7", tag("Foo.Bar.7").canonical_name());
+        assert_eq!("digest.sha2.256", tag("sha256").canonical_name());
+    }
+}
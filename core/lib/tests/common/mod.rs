@@ -0,0 +1,15 @@
+//! Helpers shared by the integration tests
+#![allow(dead_code)]
+
+use cde::Object;
+use data_encoding::HEXLOWER_PERMISSIVE;
+
+/// Decodes a hex string from a test vector
+pub fn hex(s: &str) -> Vec<u8> {
+    HEXLOWER_PERMISSIVE.decode(s.as_bytes()).unwrap()
+}
+
+/// Creates a key object of the type with the hex encoded key data
+pub fn key(name: &str, data: &str) -> Object {
+    Object::from_type(name, &hex(data)).unwrap()
+}
@@ -0,0 +1,98 @@
+mod convert {
+    use cde::{idx, CryptoData, Tag, TagBuilder};
+    use std::collections::{BTreeMap, HashMap};
+    use std::convert::TryFrom;
+
+    #[test]
+    fn from_str() {
+        let tt: Tag = "key.ed25519.secret".parse().unwrap();
+        assert_eq!(idx('k'), tt.class());
+        assert_eq!(idx('e'), tt.subclass());
+        assert_eq!(1, tt.subsubclass());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_str_invalid() {
+        let _tt: Tag = "key.ed25519".parse().unwrap();
+    }
+
+    #[test]
+    fn try_from_str() {
+        let tt = Tag::try_from("claim.oberon").unwrap();
+        assert_eq!(TagBuilder::from_tag("claim.oberon").build().unwrap(), tt);
+    }
+
+    #[test]
+    fn try_from_bytes() {
+        let mut tt: Tag = "key.ed25519.public".parse().unwrap();
+        tt.set_data_length(32);
+        let mut b = [0u8; 3];
+        tt.bytes(&mut b);
+        assert_eq!(tt, Tag::try_from(&b[..]).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn try_from_short_bytes() {
+        let b = [0x28u8, 0x40];
+        let _tt = Tag::try_from(&b[..]).unwrap();
+    }
+
+    #[test]
+    fn from_encoded() {
+        let tt = Tag::from_encoded(b"keeA48J-DbADmUdvXrUjJ_r4XkZv4TEtHRVoFS6oQ0AgY5i").unwrap();
+        assert_eq!("key.ed25519.secret", format!("{}", tt));
+        assert_eq!(32, tt.get_data_length());
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_encoded_truncated() {
+        // the length needs a second encoding unit that isn't there
+        let _tt = Tag::from_encoded(b"cod7").unwrap();
+    }
+
+    #[test]
+    fn hash_map_key() {
+        let mut m = HashMap::new();
+        m.insert("key.ed25519.public".parse::<Tag>().unwrap(), "public");
+        m.insert("key.ed25519.secret".parse::<Tag>().unwrap(), "secret");
+        assert_eq!(
+            Some(&"public"),
+            m.get(&"key.ed25519.public".parse().unwrap())
+        );
+        assert_eq!(
+            Some(&"secret"),
+            m.get(&"key.ed25519.secret".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn btree_map_key() {
+        let mut m = BTreeMap::new();
+        m.insert("key.ed25519.secret".parse::<Tag>().unwrap(), 2);
+        m.insert("aead.aes256-gcm".parse::<Tag>().unwrap(), 0);
+        m.insert("key.ed25519.public".parse::<Tag>().unwrap(), 1);
+        let v: Vec<i32> = m.values().cloned().collect();
+        assert_eq!(vec![0, 1, 2], v);
+    }
+
+    #[test]
+    fn data_length_is_compared() {
+        let a = TagBuilder::from_tag("key.ed25519.public").build().unwrap();
+        let mut b = a;
+        b.set_data_length(32);
+        assert_ne!(a, b);
+        assert!(a < b);
+        assert!(b < "key.ed25519.secret".parse::<Tag>().unwrap());
+
+        let mut m = HashMap::new();
+        m.insert(b, "public");
+        assert_eq!(None, m.get(&a));
+        b.set_data_length(0);
+        assert_eq!(a, b);
+        b.set_data_length(32);
+        assert_eq!(Some(&"public"), m.get(&b));
+    }
+}
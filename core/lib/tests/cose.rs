@@ -0,0 +1,139 @@
+#![cfg(feature = "cose")]
+
+mod common;
+
+mod cose {
+    use crate::common::{hex, key};
+    use cde::{cose, Error, Object};
+
+    // RFC 9052 C.7.1 "meriadoc.brandybuck@buckland.example" public key
+    const P256_PUBLIC_KEY: &str = "a5200121582065eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d2258201e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c01020258246d65726961646f632e6272616e64796275636b406275636b6c616e642e6578616d706c65";
+    // RFC 9052 C.7.2 the same key with the private key
+    const P256_PRIVATE_KEY: &str = "a601020258246d65726961646f632e6272616e64796275636b406275636b6c616e642e6578616d706c65200121582065eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d2258201e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c235820aff907c99f9ad3aae6c4cdf21122bce2bd68b5283e6907154ad911840fa208cf";
+    // RFC 9052 C.7.2 "our-secret" symmetric key
+    const OUR_SECRET: &str = "a3205820849b57219dae48de646d07dbb533566e976686457c1491be3a76dcea6c4271880104024a6f75722d736563726574";
+
+    const P256_D: &str = "aff907c99f9ad3aae6c4cdf21122bce2bd68b5283e6907154ad911840fa208cf";
+    const P256_POINT: &str = "0465eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d1e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c";
+
+    // RFC 8032 test 1
+    const ED25519_SECRET: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const ED25519_PUBLIC: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    #[test]
+    fn p256_vectors() {
+        let public = key("key.p256.public", P256_POINT);
+        let secret = key("key.p256.secret", P256_D);
+        assert_eq!(public, cose::from_cose_key(&hex(P256_PUBLIC_KEY)).unwrap());
+        assert_eq!(secret, cose::from_cose_key(&hex(P256_PRIVATE_KEY)).unwrap());
+        assert_eq!(
+            public,
+            cose::public_from_cose_key(&hex(P256_PRIVATE_KEY)).unwrap()
+        );
+    }
+
+    #[test]
+    fn p256_export() {
+        let public = key("key.p256.public", P256_POINT);
+        let secret = key("key.p256.secret", P256_D);
+        let b = cose::secret_to_cose_key(&secret, &public).unwrap();
+        // kty: EC2, crv: P-256 and no alg
+        assert!(b.starts_with(&hex("a50102200121582065eda5a1")));
+        assert_eq!(secret, cose::from_cose_key(&b).unwrap());
+        assert_eq!(public, cose::public_from_cose_key(&b).unwrap());
+    }
+
+    #[test]
+    fn signing_keys_have_alg() {
+        let verifying = key("key.p256.verifying", P256_POINT);
+        let b = cose::to_cose_key(&verifying).unwrap();
+        // kty: EC2, alg: ES256
+        assert!(b.starts_with(&hex("a501020326")));
+        assert_eq!(verifying, cose::from_cose_key(&b).unwrap());
+
+        let signing = key("key.p256.signing", P256_D);
+        let b = cose::to_cose_key(&signing).unwrap();
+        assert_eq!(signing, cose::from_cose_key(&b).unwrap());
+        assert!(cose::public_from_cose_key(&b).is_err());
+
+        let public = key("key.p256.public", P256_POINT);
+        assert!(matches!(
+            cose::secret_to_cose_key(&signing, &public),
+            Err(Error::InvalidKey { .. })
+        ));
+    }
+
+    #[test]
+    fn ed25519() {
+        let public = key("key.ed25519.public", ED25519_PUBLIC);
+        let secret = key("key.ed25519.secret", ED25519_SECRET);
+        let b = cose::to_cose_key(&public).unwrap();
+        // kty: OKP, alg: EdDSA, crv: Ed25519
+        let mut expected = hex("a4010103272006215820");
+        expected.extend_from_slice(&hex(ED25519_PUBLIC));
+        assert_eq!(expected, b);
+        assert_eq!(public, cose::from_cose_key(&b).unwrap());
+
+        let b = cose::secret_to_cose_key(&secret, &public).unwrap();
+        assert_eq!(secret, cose::from_cose_key(&b).unwrap());
+        assert_eq!(public, cose::public_from_cose_key(&b).unwrap());
+    }
+
+    #[test]
+    fn x25519() {
+        let public = Object::from_type("key.x25519.public", &[9u8; 32]).unwrap();
+        let b = cose::to_cose_key(&public).unwrap();
+        // kty: OKP, crv: X25519 and no alg
+        assert!(b.starts_with(&hex("a30101200421")));
+        assert_eq!(public, cose::from_cose_key(&b).unwrap());
+    }
+
+    #[test]
+    fn compressed_point() {
+        let mut point = vec![3u8];
+        point.extend_from_slice(&[7u8; 32]);
+        let public = Object::from_type("key.k256.public", &point).unwrap();
+        let b = cose::to_cose_key(&public).unwrap();
+        // y is the sign bit
+        assert!(b.ends_with(&hex("22f5")));
+        assert_eq!(public, cose::from_cose_key(&b).unwrap());
+    }
+
+    #[test]
+    fn symmetric() {
+        let k = cose::from_cose_key(&hex(OUR_SECRET)).unwrap();
+        assert_eq!("key.aes.256", k.tag().canonical_name());
+        assert_eq!(
+            hex("849b57219dae48de646d07dbb533566e976686457c1491be3a76dcea6c427188"),
+            k.data()
+        );
+        let b = cose::to_cose_key(&k).unwrap();
+        // kty: Symmetric, alg: A256GCM
+        assert!(b.starts_with(&hex("a30104030320")));
+        assert_eq!(k, cose::from_cose_key(&b).unwrap());
+        assert!(cose::public_from_cose_key(&b).is_err());
+    }
+
+    #[test]
+    fn unsupported() {
+        // kty: EC2, crv: P-384
+        assert!(matches!(
+            cose::from_cose_key(&hex("a201022002")),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+        // kty: OKP, alg: ES256, crv: Ed25519
+        assert!(matches!(
+            cose::from_cose_key(&hex("a3010103262006")),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+        assert!(matches!(
+            cose::from_cose_key(&hex("80")),
+            Err(Error::CoseError(_))
+        ));
+        let rsa = Object::from_type("key.rsa.public", &[1u8; 16]).unwrap();
+        assert!(matches!(
+            cose::to_cose_key(&rsa),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+    }
+}
@@ -2,14 +2,9 @@ use cde::{CryptoData, decode_tag_and_data, ENCODER, TagBuilder};
 use std::fmt::{self, Display, Formatter};
 //use rand::{thread_rng, Rng};
 
+#[derive(Default)]
 struct Key([u8; 32]);
 
-impl Default for Key {
-    fn default() -> Self {
-        Key([0u8; 32])
-    }
-}
-
 impl AsMut<[u8]> for Key {
     fn as_mut(&mut self) -> &mut [u8] {
         &mut self.0
@@ -74,7 +69,7 @@ fn decode2() {
 
     // local buffer used for decoding the tag and data into
     let mut buf = [0u8; 32];
-    assert_eq!(buf.len(), tag.get_data_length() as usize);
+    assert_eq!(buf.len(), tag.get_data_length());
 
     // decode the rest of the string
     ENCODER.decode_mut(&encoded[tag.encode_len()..], &mut buf).unwrap();
@@ -0,0 +1,142 @@
+#![cfg(feature = "der")]
+
+mod common;
+
+mod der {
+    use crate::common::{hex, key};
+    use cde::{der, Error, Object};
+
+    // RFC 8032 test 1
+    const ED25519_SECRET: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const ED25519_PUBLIC: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    // RFC 7748 section 6.1
+    const X25519_SECRET: &str = "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a";
+
+    // RFC 9052 C.7.1 "meriadoc.brandybuck@buckland.example"
+    const P256_D: &str = "aff907c99f9ad3aae6c4cdf21122bce2bd68b5283e6907154ad911840fa208cf";
+    const P256_POINT: &str = "0465eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d1e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c";
+
+    // PKCS#8 generated by openssl from the same key, it includes the public key
+    const P256_PKCS8: &str = "308187020100301306072a8648ce3d020106082a8648ce3d030107046d306b0201010420aff907c99f9ad3aae6c4cdf21122bce2bd68b5283e6907154ad911840fa208cfa1440342000465eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d1e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c";
+    const P256_SPKI: &str = "3059301306072a8648ce3d020106082a8648ce3d0301070342000465eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d1e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c";
+
+    #[test]
+    fn ed25519_secret() {
+        let obj = key("key.ed25519.secret", ED25519_SECRET);
+        let der = der::to_pkcs8(&obj).unwrap();
+        let mut expected = hex("302e020100300506032b657004220420");
+        expected.extend_from_slice(&hex(ED25519_SECRET));
+        assert_eq!(expected, der);
+        assert_eq!(obj, der::from_pkcs8(&der).unwrap());
+    }
+
+    #[test]
+    fn ed25519_public() {
+        let obj = key("key.ed25519.public", ED25519_PUBLIC);
+        let der = der::to_spki(&obj).unwrap();
+        let mut expected = hex("302a300506032b6570032100");
+        expected.extend_from_slice(&hex(ED25519_PUBLIC));
+        assert_eq!(expected, der);
+        assert_eq!(obj, der::from_spki(&der).unwrap());
+    }
+
+    #[test]
+    fn x25519_secret() {
+        let obj = key("key.x25519.secret", X25519_SECRET);
+        let der = der::to_pkcs8(&obj).unwrap();
+        assert_eq!(hex("302e020100300506032b656e04220420"), &der[..16]);
+        assert_eq!(obj, der::from_der(&der).unwrap());
+    }
+
+    #[test]
+    fn p256_secret() {
+        let obj = key("key.p256.secret", P256_D);
+        assert_eq!(obj, der::from_pkcs8(&hex(P256_PKCS8)).unwrap());
+        let der = der::to_pkcs8(&obj).unwrap();
+        assert_eq!(obj, der::from_pkcs8(&der).unwrap());
+    }
+
+    #[test]
+    fn p256_public() {
+        let obj = key("key.p256.public", P256_POINT);
+        assert_eq!(hex(P256_SPKI), der::to_spki(&obj).unwrap());
+        assert_eq!(obj, der::from_spki(&hex(P256_SPKI)).unwrap());
+    }
+
+    #[test]
+    fn p256_verifying_key() {
+        let obj = key("key.p256.verifying", P256_POINT);
+        assert_eq!(hex(P256_SPKI), der::to_der(&obj).unwrap());
+    }
+
+    #[test]
+    fn k256_round_trip() {
+        let secret = key("key.k256.secret", P256_D);
+        let der = der::to_der(&secret).unwrap();
+        assert_eq!(secret, der::from_der(&der).unwrap());
+
+        let public = key("key.k256.public", &P256_POINT[..66].replacen("04", "02", 1));
+        let der = der::to_der(&public).unwrap();
+        assert_eq!(public, der::from_der(&der).unwrap());
+    }
+
+    #[test]
+    fn rsa() {
+        let pkcs1 = include_bytes!("data/rsa1024.pkcs1.der");
+        let pkcs8 = include_bytes!("data/rsa1024.pk8.der");
+        let obj = Object::from_type("key.rsa.secret", pkcs1).unwrap();
+        assert_eq!(&pkcs8[..], &der::to_pkcs8(&obj).unwrap()[..]);
+        assert_eq!(obj, der::from_pkcs8(pkcs8).unwrap());
+
+        let pkcs1 = include_bytes!("data/rsa1024.pub.pkcs1.der");
+        let spki = include_bytes!("data/rsa1024.spki.der");
+        let obj = Object::from_type("key.rsa.public", pkcs1).unwrap();
+        assert_eq!(&spki[..], &der::to_spki(&obj).unwrap()[..]);
+        assert_eq!(obj, der::from_spki(spki).unwrap());
+    }
+
+    #[test]
+    fn wrong_key_size() {
+        let obj = Object::from_type("key.ed25519.secret", &[0u8; 31]).unwrap();
+        match der::to_pkcs8(&obj).unwrap_err() {
+            Error::InvalidKey { name, .. } => assert_eq!("key.ed25519.secret", name),
+            e => panic!("unexpected error: {}", e),
+        }
+        let obj = Object::from_type("key.p256.public", &[5u8; 65]).unwrap();
+        assert!(der::to_spki(&obj).is_err());
+    }
+
+    #[test]
+    fn wrong_half() {
+        let obj = key("key.ed25519.public", ED25519_PUBLIC);
+        assert!(der::to_pkcs8(&obj).is_err());
+        let obj = key("key.ed25519.secret", ED25519_SECRET);
+        assert!(der::to_spki(&obj).is_err());
+    }
+
+    #[test]
+    fn unsupported_type() {
+        let obj = Object::from_type("key.bls12381.public", &[0u8; 96]).unwrap();
+        match der::to_der(&obj).unwrap_err() {
+            Error::UnsupportedType(name) => assert_eq!("key.bls12381.public", name),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn unsupported_algorithm() {
+        // ed448 SubjectPublicKeyInfo
+        let mut spki = hex("3043300506032b6571033a00");
+        spki.extend_from_slice(&[1u8; 57]);
+        match der::from_spki(&spki).unwrap_err() {
+            Error::UnsupportedAlgorithm(_) => {}
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn not_der() {
+        assert!(der::from_der(b"not der").is_err());
+    }
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "did")]
+
+mod common;
+
+mod did {
+    use crate::common::key;
+    use cde::{did, Error, Object};
+
+    // the example from the did:key spec
+    const SPEC_DID: &str = "did:key:z6MkhaXgBZDvotDkL5257faiztiGiC2QtKLGpbnnEGta2doK";
+    const SPEC_KEY: &str = "2e6fcce36701dc791488e0d0b1745cc1e33a4c1c9fcc41c63bd343dbbe0970e6";
+
+    // RFC 8032 test 1
+    const ED25519_PUBLIC: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const ED25519_DID: &str = "did:key:z6MktwupdmLXVVqTzCw4i46r4uGyosGXRnR3XjN4Zq7oMMsw";
+
+    // RFC 9052 C.7.1 "meriadoc.brandybuck@buckland.example"
+    const P256_POINT: &str = "0465eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d1e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c";
+    const P256_COMPRESSED: &str =
+        "0265eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d";
+    const P256_DID: &str = "did:key:zDnaeXHrQ9NtnV7US8JhtnAkaS8dnMW5T8rBrV9UdLC2w685r";
+
+    #[test]
+    fn spec_example() {
+        let public = did::from_did(SPEC_DID).unwrap();
+        assert_eq!(key("key.ed25519.public", SPEC_KEY), public);
+        assert_eq!(SPEC_DID, did::to_did(&public).unwrap());
+    }
+
+    #[test]
+    fn ed25519() {
+        let public = key("key.ed25519.public", ED25519_PUBLIC);
+        assert_eq!(ED25519_DID, did::to_did(&public).unwrap());
+        assert_eq!(public, did::from_did(ED25519_DID).unwrap());
+        let fragment = format!("{}#{}", ED25519_DID, &ED25519_DID[8..]);
+        assert_eq!(public, did::from_did(&fragment).unwrap());
+    }
+
+    #[test]
+    fn p256_is_compressed() {
+        let public = key("key.p256.public", P256_POINT);
+        assert_eq!(P256_DID, did::to_did(&public).unwrap());
+        assert_eq!(
+            key("key.p256.public", P256_COMPRESSED),
+            did::from_did(P256_DID).unwrap()
+        );
+    }
+
+    #[test]
+    fn prefixes() {
+        for (name, len, prefix) in [
+            ("key.x25519.public", 32, "did:key:z6LS"),
+            ("key.k256.public", 33, "did:key:zQ3s"),
+            ("key.bls12381.public", 96, "did:key:zUC"),
+        ]
+        .iter()
+        {
+            let mut data = vec![7u8; *len];
+            data[0] = 2;
+            let public = Object::from_type(name, &data).unwrap();
+            let id = did::to_did(&public).unwrap();
+            assert!(id.starts_with(prefix), "{}", id);
+            assert_eq!(public, did::from_did(&id).unwrap());
+        }
+    }
+
+    #[test]
+    fn did_object() {
+        let public = key("key.ed25519.public", ED25519_PUBLIC);
+        let obj = did::to_did_object(&public).unwrap();
+        assert_eq!("identifier.did", obj.tag().canonical_name());
+        assert_eq!(ED25519_DID.as_bytes(), obj.data());
+        assert_eq!(public, did::from_did_object(&obj).unwrap());
+        assert!(matches!(
+            did::from_did_object(&public),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+
+    #[test]
+    fn errors() {
+        let secret = Object::from_type("key.ed25519.secret", &[1u8; 32]).unwrap();
+        assert!(matches!(
+            did::to_did(&secret),
+            Err(Error::UnsupportedType(_))
+        ));
+        assert!(matches!(
+            did::from_did("did:web:example.com"),
+            Err(Error::InvalidDid(_))
+        ));
+        assert!(matches!(
+            did::from_did("did:key:f1234"),
+            Err(Error::InvalidDid(_))
+        ));
+        assert!(matches!(
+            did::from_did("did:key:z0OIl"),
+            Err(Error::InvalidDid(_))
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "digest")]
+
+mod common;
+
+mod digest {
+    use crate::common::hex;
+    use cde::{digest, multihash, Error, Object, Tag};
+
+    // the digests of "abc" from the standards' test vectors
+    static ABC: [(&str, &str); 18] = [
+        ("digest.sha1", "a9993e364706816aba3e25717850c26c9cd0d89d"),
+        ("digest.sha2.224", "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7"),
+        ("digest.sha2.256", "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"),
+        ("digest.sha2.384", "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed8086072ba1e7cc2358baeca134c825a7"),
+        ("digest.sha2.512", "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"),
+        ("digest.sha2.512/224", "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa"),
+        ("digest.sha2.512/256", "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23"),
+        ("digest.sha3.224", "e642824c3f8cf24ad09234ee7d3c766fc9a3a5168d0c94ad73b46fdf"),
+        ("digest.sha3.256", "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"),
+        ("digest.sha3.384", "ec01498288516fc926459f58e2c6ad8df9b473cb0fc08c2596da7cf0e49be4b298d88cea927ac7f539f1edf228376d25"),
+        ("digest.sha3.512", "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"),
+        ("digest.sha3.shake128", "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc8"),
+        ("digest.sha3.shake256", "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4"),
+        ("digest.blake2.b", "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"),
+        ("digest.blake2.s", "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982"),
+        ("digest.md.2", "da853b0d3f88d99b30283a69e6ded6bb"),
+        ("digest.md.4", "a448017aaf21d8525fc10ae87aa6729d"),
+        ("digest.md.5", "900150983cd24fb0d6963f7d28e17f72"),
+    ];
+
+    fn tag(name: &str) -> Tag {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn abc() {
+        for (name, expected) in &ABC {
+            let d = digest::digest(tag(name), b"abc").unwrap();
+            assert_eq!(*name, d.tag().canonical_name());
+            assert_eq!(hex(expected), d.data(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn streaming() {
+        for (name, expected) in &ABC {
+            let mut h = digest::Hasher::new(tag(name)).unwrap();
+            h.update(b"a");
+            h.update(b"");
+            h.update(b"bc");
+            assert_eq!(hex(expected), h.finalize().data(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn multihash_sizes() {
+        // every digest with a multihash code is the size multihash expects
+        for (name, _) in &ABC {
+            let d = digest::digest(tag(name), b"abc").unwrap();
+            if multihash::code(&d.tag(), d.data().len()).is_ok() {
+                assert_eq!(
+                    d,
+                    multihash::decode(&multihash::encode(&d).unwrap()).unwrap()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn check() {
+        let expected = Object::from_type("digest.sha2.256", &hex(ABC[2].1)).unwrap();
+        digest::check(&expected, b"abc").unwrap();
+        assert!(matches!(
+            digest::check(&expected, b"abd"),
+            Err(Error::DigestMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn unsupported() {
+        assert!(matches!(
+            digest::Hasher::new(tag("digest.md.6")),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+        assert!(matches!(
+            digest::digest(tag("key.aes.256"), b"abc"),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "ed25519")]
+
+mod common;
+
+mod ed25519 {
+    use crate::common::{hex, key};
+    use cde::{Error, Object};
+    use ed25519_dalek::{SigningKey, VerifyingKey};
+    use std::convert::TryFrom;
+
+    // RFC 8032 test 1
+    const SECRET: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const PUBLIC: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    #[test]
+    fn signing_key() {
+        let secret = key("key.ed25519.secret", SECRET);
+        let signing = SigningKey::try_from(&secret).unwrap();
+        assert_eq!(hex(PUBLIC), signing.verifying_key().as_bytes());
+        assert_eq!(secret, Object::from(&signing));
+    }
+
+    #[test]
+    fn verifying_key() {
+        let public = key("key.ed25519.public", PUBLIC);
+        let verifying = VerifyingKey::try_from(&public).unwrap();
+        assert_eq!(public, Object::from(&verifying));
+    }
+
+    #[test]
+    fn wrong_half() {
+        let public = key("key.ed25519.public", PUBLIC);
+        assert!(matches!(
+            SigningKey::try_from(&public),
+            Err(Error::InvalidKey { .. })
+        ));
+        let secret = key("key.ed25519.secret", SECRET);
+        assert!(matches!(
+            VerifyingKey::try_from(&secret),
+            Err(Error::InvalidKey { .. })
+        ));
+    }
+
+    #[test]
+    fn wrong_type() {
+        let secret = key("key.x25519.secret", SECRET);
+        assert!(matches!(
+            SigningKey::try_from(&secret),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+
+    #[test]
+    fn wrong_size() {
+        let secret = key("key.ed25519.secret", &SECRET[2..]);
+        assert!(matches!(
+            SigningKey::try_from(&secret),
+            Err(Error::InvalidKey { .. })
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use cde::{CryptoData, ENCODER, encode_tag_and_data, TagBuilder};
+use cde::{CryptoData, ENCODER, encode_tag_and_data, Tag, TagBuilder};
 
 #[test]
 fn encode1() {
@@ -81,6 +81,24 @@ fn encode6() {
     assert_eq!("--if", s);
 }
 
+#[test]
+fn encode6_subsubclass() {
+    // the sub-sub-class uses all four bits of its nibble
+    let mut tt = TagBuilder::from_tag("list.list.12").build().unwrap();
+    tt.set_data_length(5);
+
+    let mut b = [0u8; 4];
+    let len = tt.encode(&mut b);
+    assert_eq!(len, tt.encode_len());
+    let s = core::str::from_utf8(&b).unwrap();
+
+    assert_eq!("--Qf", s);
+    assert_eq!(12, Tag::from_encoded(&b).unwrap().subsubclass());
+
+    // and there are only four bits
+    assert!(TagBuilder::from_tag("list.list.16").build().is_err());
+}
+
 #[test]
 fn encode7() {
     let mut tt = TagBuilder::from_tag("list.list").build().unwrap();
@@ -112,12 +130,8 @@ fn encode8() {
 #[test]
 fn encode9() {
 
+    #[derive(Default)]
     struct Key([u8; 32]);
-    impl Default for Key {
-        fn default() -> Self {
-            Key([0u8; 32])
-        }
-    }
     impl AsMut<[u8]> for Key {
         fn as_mut(&mut self) -> &mut [u8] {
             &mut self.0
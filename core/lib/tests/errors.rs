@@ -0,0 +1,158 @@
+mod errors {
+    use cde::{DecodeKind, Error, Reason, Tag, TagBuilder};
+
+    fn err(s: &str) -> Error {
+        TagBuilder::from_tag(s).build().unwrap_err()
+    }
+
+    #[test]
+    fn empty_string() {
+        match err("") {
+            Error::InvalidClass { segment, reason } => {
+                assert_eq!("", segment);
+                assert_eq!(Reason::Missing, reason);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn missing_subclass() {
+        match err("key") {
+            Error::InvalidSubClass { reason, .. } => assert_eq!(Reason::Missing, reason),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn missing_subsubclass() {
+        match err("key.ed25519") {
+            Error::InvalidSubSubClass { reason, .. } => assert_eq!(Reason::Missing, reason),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn nonexp_nonstd_class() {
+        match err("foo.bar") {
+            Error::InvalidClass { segment, reason } => {
+                assert_eq!("foo", segment);
+                assert_eq!(Reason::NotExperimental, reason);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn nonstd_subclass_of_nonexp_class() {
+        match err("key.foo") {
+            Error::InvalidSubClass { segment, reason } => {
+                assert_eq!("foo", segment);
+                assert_eq!(Reason::ParentNotExperimental, reason);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn nonexp_subclass_of_exp_class() {
+        match err("Key.ed25519.public") {
+            Error::InvalidSubClass { segment, reason } => {
+                assert_eq!("ed25519", segment);
+                assert_eq!(Reason::ParentExperimental, reason);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn invalid_first_letter() {
+        match err("Foo.!bar") {
+            Error::InvalidSubClass { segment, reason } => {
+                assert_eq!("!bar", segment);
+                assert_eq!(Reason::InvalidFirstLetter, reason);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn subsubclass_not_allowed() {
+        match err("claim.oberon.0") {
+            Error::InvalidSubSubClass { segment, reason } => {
+                assert_eq!("0", segment);
+                assert_eq!(Reason::NotAllowed, reason);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn subsubclass_not_a_number() {
+        match err("key.ed25519.private") {
+            Error::InvalidSubSubClass { segment, reason } => {
+                assert_eq!("private", segment);
+                assert_eq!(Reason::NotANumber, reason);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn subsubclass_out_of_range() {
+        match err("list.list.16") {
+            Error::InvalidSubSubClass { segment, reason } => {
+                assert_eq!("16", segment);
+                assert_eq!(Reason::OutOfRange, reason);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn subsubclass_uses_four_bits() {
+        let tt = TagBuilder::from_tag("list.list.15").build().unwrap();
+        assert_eq!(15, tt.subsubclass());
+    }
+
+    #[test]
+    fn too_many_parts() {
+        match err("key.ed25519.public.extra") {
+            Error::InvalidTypeName { name, reason } => {
+                assert_eq!("key.ed25519.public.extra", name);
+                assert_eq!(Reason::TooManyParts, reason);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn message() {
+        assert_eq!(
+            "invalid sub-class 'foo': non-standard names require an experimental class",
+            format!("{}", err("key.foo"))
+        );
+    }
+
+    #[test]
+    fn encoded_invalid_symbol() {
+        match Tag::from_encoded(b"cod7a!aa").unwrap_err() {
+            Error::DecodeError { offset, kind } => {
+                assert_eq!(5, offset);
+                assert_eq!(DecodeKind::Symbol, kind);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn encoded_truncated() {
+        match Tag::from_encoded(b"cod7aA").unwrap_err() {
+            Error::DecodeError { offset, kind } => {
+                assert_eq!(6, offset);
+                assert_eq!(DecodeKind::Length, kind);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+}
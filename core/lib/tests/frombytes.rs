@@ -1,5 +1,5 @@
 mod frombytes {
-    use cde::TagBuilder;
+    use cde::{Error, TagBuilder};
 
     #[test]
     #[should_panic]
@@ -98,4 +98,16 @@ mod frombytes {
         let b = [0x0a, 0x11, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01];
         let _tt = TagBuilder::from_bytes(&b).build().unwrap();
     }
+
+    #[test]
+    fn overlong_length() {
+        // more continuation bytes than a u64 holds fail instead of overflowing
+        let mut b = vec![0x0a, 0x11];
+        b.extend_from_slice(&[0xff; 12]);
+        b.push(0x01);
+        assert!(matches!(
+            TagBuilder::from_bytes(&b).build(),
+            Err(Error::InvalidLength)
+        ));
+    }
 }
@@ -0,0 +1,100 @@
+#![cfg(feature = "hmac")]
+
+mod common;
+
+mod hmac {
+    use crate::common::hex;
+    use cde::{hmac, Error, Object, Tag, TagBuilder};
+
+    // test case 2 from RFC 4231 with the sha3 and blake2 values from python's
+    // hmac module
+    const KEY: &[u8] = b"Jefe";
+    const DATA: &[u8] = b"what do ya want for nothing?";
+
+    fn tag(s: &str) -> Tag {
+        TagBuilder::from_tag(s).build().unwrap()
+    }
+
+    fn key() -> Object {
+        Object::from_type("key.undefined", KEY).unwrap()
+    }
+
+    #[test]
+    fn vectors() {
+        let vectors = [
+            ("hmac.sha2.224", "a30e01098bc6dbbf45690f3a7e9e6d0f8bbea2a39e6148008fd05e44"),
+            ("hmac.sha2.256", "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"),
+            ("hmac.sha2.384", "af45d2e376484031617f78d2b58a6b1b9c7ef464f5a01b47e42ec3736322445e8e2240ca5e69e2c78b3239ecfab21649"),
+            ("hmac.sha2.512", "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737"),
+            ("hmac.sha2.512/224", "4a530b31a79ebcce36916546317c45f247d83241dfb818fd37254bde"),
+            ("hmac.sha2.512/256", "6df7b24630d5ccb2ee335407081a87188c221489768fa2020513b2d593359456"),
+            ("hmac.sha3.224", "7fdb8dd88bd2f60d1b798634ad386811c2cfc85bfaf5d52bbace5e66"),
+            ("hmac.sha3.256", "c7d4072e788877ae3596bbb0da73b887c9171f93095b294ae857fbe2645e1ba5"),
+            ("hmac.sha3.384", "f1101f8cbf9766fd6764d2ed61903f21ca9b18f57cf3e1a23ca13508a93243ce48c045dc007f26a21b3f5e0e9df4c20a"),
+            ("hmac.sha3.512", "5a4bfeab6166427c7a3647b747292b8384537cdb89afb3bf5665e4c5e709350b287baec921fd7ca0ee7a0c31d022a95e1fc92ba9d77df883960275beb4e62024"),
+            ("hmac.blake2.b", "6ff884f8ddc2a6586b3c98a4cd6ebdf14ec10204b6710073eb5865ade37a2643b8807c1335d107ecdb9ffeaeb6828c4625ba172c66379efcd222c2de11727ab4"),
+            ("hmac.blake2.s", "90b6281e2f3038c9056af0b4a7e763cae6fe5d9eb4386a0ec95237890c104ff0"),
+        ];
+        for (name, expected) in vectors.iter() {
+            let mac = hmac::hmac(tag(name), &key(), DATA).unwrap();
+            assert_eq!(*name, mac.tag().canonical_name());
+            assert_eq!(hex(expected), mac.data(), "{}", name);
+            hmac::verify(&mac, &key(), DATA).unwrap();
+        }
+    }
+
+    #[test]
+    fn symmetric_key() {
+        let key = Object::from_type("key.aes.256", &[7; 32]).unwrap();
+        let mac = hmac::hmac(tag("hs256"), &key, DATA).unwrap();
+        assert_eq!("hmac.sha2.256", mac.tag().canonical_name());
+        assert_eq!(32, mac.data().len());
+        hmac::verify(&mac, &key, DATA).unwrap();
+    }
+
+    #[test]
+    fn mismatch() {
+        let mac = hmac::hmac(tag("hmac.sha2.256"), &key(), DATA).unwrap();
+        assert!(matches!(
+            hmac::verify(&mac, &key(), b"what do ya want for something?"),
+            Err(Error::HmacMismatch(_))
+        ));
+
+        let other = Object::from_type("key.undefined", b"Jeff").unwrap();
+        assert!(matches!(
+            hmac::verify(&mac, &other, DATA),
+            Err(Error::HmacMismatch(_))
+        ));
+
+        // a truncated HMAC doesn't match either
+        let short = Object::new(mac.tag(), &mac.data()[..16]);
+        assert!(matches!(
+            hmac::verify(&short, &key(), DATA),
+            Err(Error::HmacMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn unsupported() {
+        assert!(matches!(
+            hmac::hmac(tag("hmac.undefined"), &key(), DATA),
+            Err(Error::UnsupportedType(_))
+        ));
+        assert!(matches!(
+            hmac::hmac(tag("digest.sha2.256"), &key(), DATA),
+            Err(Error::UnsupportedType(_))
+        ));
+        let nonce = Object::from_type("nonce.bytes", KEY).unwrap();
+        assert!(matches!(
+            hmac::hmac(tag("hmac.sha2.256"), &nonce, DATA),
+            Err(Error::UnsupportedType(_))
+        ));
+        for name in &["key.ed25519.public", "key.ed25519.secret", "key.list"] {
+            let key = Object::from_type(name, &[7; 32]).unwrap();
+            assert!(matches!(
+                hmac::hmac(tag("hmac.sha2.256"), &key, DATA),
+                Err(Error::UnsupportedType(_))
+            ));
+        }
+    }
+}
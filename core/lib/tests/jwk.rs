@@ -0,0 +1,178 @@
+#![cfg(feature = "jwk")]
+
+mod common;
+
+mod jwk {
+    use crate::common::key;
+    use cde::{jwk, Error, Object, TagBuilder};
+
+    // RFC 8037 appendix A.1 and A.2
+    const ED25519_JWK: &str = r#"{"kty":"OKP","crv":"Ed25519","d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#;
+    const ED25519_SECRET: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const ED25519_PUBLIC: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    // RFC 9052 C.7.1 "meriadoc.brandybuck@buckland.example"
+    const P256_JWK: &str = r#"{"kty":"EC","kid":"meriadoc.brandybuck@buckland.example","use":"sig","crv":"P-256","x":"Ze2loSV3wrroKUN_4zhwGhCqo3Xhu1td4QjeQ5wIVR0","y":"HlLtdXARY_f55A3fnzQbPcm6hgr34Mp8p-nuzQCE0Zw","d":"r_kHyZ-a06rmxM3yESK84r1otSg-aQcVStkRhA-iCM8"}"#;
+    const P256_D: &str = "aff907c99f9ad3aae6c4cdf21122bce2bd68b5283e6907154ad911840fa208cf";
+    const P256_POINT: &str = "0465eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d1e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c";
+
+    #[test]
+    fn ed25519() {
+        let secret = key("key.ed25519.secret", ED25519_SECRET);
+        let public = key("key.ed25519.public", ED25519_PUBLIC);
+        assert_eq!(secret, jwk::from_jwk(ED25519_JWK).unwrap());
+        assert_eq!(public, jwk::public_from_jwk(ED25519_JWK).unwrap());
+        assert_eq!(
+            r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo","d":"nWGxne_9WmC6hEr0kuwsxERJxWl7MmkZcDusAxyuf2A"}"#,
+            jwk::secret_to_jwk(&secret, &public).unwrap()
+        );
+        assert_eq!(
+            r#"{"kty":"OKP","crv":"Ed25519","x":"11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}"#,
+            jwk::to_jwk(&public).unwrap()
+        );
+    }
+
+    #[test]
+    fn p256() {
+        let secret = key("key.p256.secret", P256_D);
+        let public = key("key.p256.public", P256_POINT);
+        assert_eq!(secret, jwk::from_jwk(P256_JWK).unwrap());
+        assert_eq!(public, jwk::public_from_jwk(P256_JWK).unwrap());
+        let s = jwk::secret_to_jwk(&secret, &public).unwrap();
+        assert_eq!(secret, jwk::from_jwk(&s).unwrap());
+        assert_eq!(public, jwk::public_from_jwk(&s).unwrap());
+    }
+
+    #[test]
+    fn x25519_and_k256_round_trip() {
+        let x = Object::from_type("key.x25519.public", &[9u8; 32]).unwrap();
+        let s = jwk::to_jwk(&x).unwrap();
+        assert!(s.contains(r#""crv":"X25519""#));
+        assert_eq!(x, jwk::from_jwk(&s).unwrap());
+
+        let mut point = vec![4u8];
+        point.extend_from_slice(&[7u8; 64]);
+        let k = Object::from_type("key.k256.public", &point).unwrap();
+        let s = jwk::to_jwk(&k).unwrap();
+        assert!(s.contains(r#""crv":"secp256k1""#));
+        assert_eq!(k, jwk::from_jwk(&s).unwrap());
+    }
+
+    #[test]
+    fn aes() {
+        let k = Object::from_type("key.aes.256", &[0x5a; 32]).unwrap();
+        let s = jwk::to_jwk(&k).unwrap();
+        assert_eq!(
+            r#"{"kty":"oct","k":"WlpaWlpaWlpaWlpaWlpaWlpaWlpaWlpaWlpaWlpaWlo"}"#,
+            s
+        );
+        assert_eq!(k, jwk::from_jwk(&s).unwrap());
+        assert!(jwk::public_from_jwk(&s).is_err());
+
+        let k = Object::from_type("key.aes.128", &[1u8; 16]).unwrap();
+        assert_eq!(k, jwk::from_jwk(&jwk::to_jwk(&k).unwrap()).unwrap());
+
+        let k = Object::from_type("key.aes.128", &[1u8; 32]).unwrap();
+        assert!(matches!(jwk::to_jwk(&k), Err(Error::InvalidKey { .. })));
+    }
+
+    #[test]
+    fn secret_needs_public() {
+        let secret = key("key.ed25519.secret", ED25519_SECRET);
+        assert!(matches!(
+            jwk::to_jwk(&secret),
+            Err(Error::InvalidKey { .. })
+        ));
+        let public = key("key.p256.public", P256_POINT);
+        assert!(matches!(
+            jwk::secret_to_jwk(&secret, &public),
+            Err(Error::InvalidKey { .. })
+        ));
+    }
+
+    #[test]
+    fn compressed_point() {
+        let mut point = vec![2u8];
+        point.extend_from_slice(&[7u8; 32]);
+        let k = Object::from_type("key.p256.public", &point).unwrap();
+        assert!(matches!(jwk::to_jwk(&k), Err(Error::InvalidKey { .. })));
+    }
+
+    #[test]
+    fn unsupported() {
+        let rsa = r#"{"kty":"RSA","n":"AQAB","e":"AQAB"}"#;
+        assert!(matches!(
+            jwk::from_jwk(rsa),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+        let p384 = r#"{"kty":"EC","crv":"P-384","x":"AA","y":"AA"}"#;
+        assert!(matches!(
+            jwk::from_jwk(p384),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+        assert!(matches!(
+            jwk::from_jwk(r#"{"kty":"OKP","crv":"Ed25519"}"#),
+            Err(Error::JwkError(_))
+        ));
+        assert!(matches!(jwk::from_jwk("{"), Err(Error::JwkError(_))));
+    }
+
+    #[test]
+    fn set() {
+        let doc = format!(r#"{{"keys":[{},{}]}}"#, ED25519_JWK, P256_JWK);
+        let (list, skipped) = jwk::from_jwk_set(&doc).unwrap();
+        assert!(skipped.is_empty());
+        assert_eq!("key.list", list.tag().canonical_name());
+        let items = list.items().unwrap();
+        assert_eq!(
+            vec![
+                key("key.ed25519.secret", ED25519_SECRET),
+                key("key.ed25519.public", ED25519_PUBLIC),
+                key("key.p256.secret", P256_D),
+                key("key.p256.public", P256_POINT),
+            ],
+            items
+        );
+        assert_eq!(4, list.tag().get_data_length());
+
+        let out = jwk::to_jwk_set(&list).unwrap();
+        assert_eq!(list, jwk::from_jwk_set(&out).unwrap().0);
+    }
+
+    #[test]
+    fn set_of_public_keys() {
+        let tt = TagBuilder::from_tag("key.list").build().unwrap();
+        let aes = Object::from_type("key.aes.128", &[3u8; 16]).unwrap();
+        let list = Object::list(tt, &[key("key.ed25519.public", ED25519_PUBLIC), aes]);
+        let out = jwk::to_jwk_set(&list).unwrap();
+        assert!(out.starts_with(r#"{"keys":[{"kty":"OKP""#));
+        assert_eq!(list, jwk::from_jwk_set(&out).unwrap().0);
+
+        let lone = Object::list(tt, &[key("key.ed25519.secret", ED25519_SECRET)]);
+        assert!(jwk::to_jwk_set(&lone).is_err());
+    }
+
+    #[test]
+    fn set_skips_unsupported_keys() {
+        let rsa = r#"{"kty":"RSA","kid":"rsa-1","n":"AQAB","e":"AQAB"}"#;
+        let p384 = r#"{"kty":"EC","crv":"P-384","x":"AA","y":"AA"}"#;
+        let doc = format!(r#"{{"keys":[{},{},{}]}}"#, rsa, P256_JWK, p384);
+        let (list, skipped) = jwk::from_jwk_set(&doc).unwrap();
+        assert_eq!(
+            vec![
+                key("key.p256.secret", P256_D),
+                key("key.p256.public", P256_POINT),
+            ],
+            list.items().unwrap()
+        );
+        assert_eq!(2, skipped.len());
+        assert_eq!(Some("rsa-1"), skipped[0].kid.as_deref());
+        assert!(matches!(skipped[0].error, Error::UnsupportedAlgorithm(_)));
+        assert_eq!(None, skipped[1].kid);
+
+        // a broken key of a supported type still fails the import
+        let broken = r#"{"kty":"OKP","crv":"Ed25519"}"#;
+        let doc = format!(r#"{{"keys":[{},{}]}}"#, P256_JWK, broken);
+        assert!(matches!(jwk::from_jwk_set(&doc), Err(Error::JwkError(_))));
+    }
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "k256")]
+
+mod common;
+
+mod k256 {
+    use crate::common::key;
+    use cde::{Error, Object};
+    use std::convert::TryFrom;
+
+    // the secp256k1 generator is the public key for the scalar 1
+    const D: &str = "0000000000000000000000000000000000000000000000000000000000000001";
+    const POINT: &str = "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8";
+
+    #[test]
+    fn keys() {
+        use k256::{
+            ecdsa::{SigningKey, VerifyingKey},
+            PublicKey, SecretKey,
+        };
+
+        let secret = key("key.k256.secret", D);
+        let sk = SecretKey::try_from(&secret).unwrap();
+        assert_eq!(secret, Object::from(&sk));
+        assert_eq!(
+            key("key.k256.public", POINT),
+            Object::from(&sk.public_key())
+        );
+
+        let signing = SigningKey::try_from(&key("key.k256.signing", D)).unwrap();
+        let vk = VerifyingKey::try_from(&key("key.k256.verifying", POINT)).unwrap();
+        assert_eq!(signing.verifying_key(), &vk);
+        assert_eq!(key("key.k256.verifying", POINT), Object::from(&vk));
+        let pk = PublicKey::try_from(&key("key.k256.public", POINT)).unwrap();
+        assert_eq!(sk.public_key(), pk);
+    }
+
+    #[test]
+    fn invalid() {
+        use k256::SecretKey;
+
+        // the scalar must be less than the group order
+        assert!(matches!(
+            SecretKey::try_from(&key(
+                "key.k256.secret",
+                "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+            )),
+            Err(Error::InvalidKey { .. })
+        ));
+        assert!(matches!(
+            SecretKey::try_from(&key("key.p256.secret", D)),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+#![cfg(feature = "kdf")]
+
+mod common;
+
+mod kdf {
+    use crate::common::hex;
+    use cde::{
+        kdf::{self, Argon2Variant, Params},
+        Error, Object, Tag, TagBuilder,
+    };
+
+    fn tag(s: &str) -> Tag {
+        TagBuilder::from_tag(s).build().unwrap()
+    }
+
+    fn roundtrip(params: &Params) {
+        let obj = params.to_object().unwrap();
+        assert_eq!("Kdf.list", obj.tag().canonical_name());
+        let obj = Object::from_encoded(obj.to_string().as_bytes()).unwrap();
+        assert_eq!(*params, Params::from_object(&obj).unwrap());
+    }
+
+    #[test]
+    fn hkdf() {
+        // test case 1 from RFC 5869 truncated to 32 bytes
+        let params = Params::Hkdf {
+            hash: tag("hmac.sha2.256"),
+            salt: hex("000102030405060708090a0b0c"),
+            info: hex("f0f1f2f3f4f5f6f7f8f9"),
+        };
+        let key = kdf::derive(&params, &[0x0b; 22], tag("key.aes.256")).unwrap();
+        assert_eq!("key.aes.256", key.tag().canonical_name());
+        assert_eq!(
+            hex("3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf"),
+            key.data()
+        );
+        roundtrip(&params);
+    }
+
+    #[test]
+    fn pbkdf2() {
+        let params = Params::Pbkdf2 {
+            hash: tag("hmac.sha2.256"),
+            salt: b"salt".to_vec(),
+            iterations: 4096,
+        };
+        let key = kdf::derive(&params, b"password", tag("key.chacha20")).unwrap();
+        assert_eq!(
+            hex("c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a"),
+            key.data()
+        );
+        roundtrip(&params);
+
+        let params = Params::Pbkdf2 {
+            hash: tag("hmac.sha2.512"),
+            salt: b"salt".to_vec(),
+            iterations: 2,
+        };
+        let key = kdf::derive(&params, b"password", tag("key.aes.128")).unwrap();
+        assert_eq!(hex("e1d9c16aa681708a45f5c7c4e215ceb6"), key.data());
+    }
+
+    #[test]
+    fn scrypt() {
+        // the first test vector from RFC 7914 truncated to 32 bytes
+        let params = Params::Scrypt {
+            salt: vec![],
+            log_n: 4,
+            r: 1,
+            p: 1,
+        };
+        let key = kdf::derive(&params, b"", tag("key.ed25519.secret")).unwrap();
+        assert_eq!(
+            hex("77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442"),
+            key.data()
+        );
+        roundtrip(&params);
+    }
+
+    #[test]
+    fn argon2() {
+        let params = Params::Argon2 {
+            variant: Argon2Variant::Argon2id,
+            salt: b"saltsaltsalt".to_vec(),
+            memory: 64,
+            iterations: 2,
+            parallelism: 1,
+        };
+        let key = kdf::derive(&params, b"password", tag("key.x25519.secret")).unwrap();
+        assert_eq!(
+            hex("6454fdcc147d7cf44f023d40df511c21189f0a0cc26016a385e235e2a6c3bd5b"),
+            key.data()
+        );
+        roundtrip(&params);
+
+        // the memory is padded to a four byte VarUInt
+        let params = Params::Argon2 {
+            variant: Argon2Variant::Argon2i,
+            salt: b"saltsaltsalt".to_vec(),
+            memory: 19456,
+            iterations: 2,
+            parallelism: 1,
+        };
+        roundtrip(&params);
+    }
+
+    #[test]
+    fn encoding() {
+        let params = Params::Pbkdf2 {
+            hash: tag("hmac.sha2.256"),
+            salt: b"salt".to_vec(),
+            iterations: 600000,
+        };
+        let items = params.to_object().unwrap().items().unwrap();
+        assert_eq!(3, items.len());
+        assert_eq!("Kdf.pbkdf2", items[0].tag().canonical_name());
+        assert!(items[0].tag().is_exp_class());
+        assert_eq!("nonce.bytes", items[1].tag().canonical_name());
+        assert_eq!(b"salt", items[1].data());
+        assert_eq!("hmac.sha2.256", items[2].tag().canonical_name());
+        assert!(items[2].data().is_empty());
+        assert_eq!(tag("Kdf.pbkdf2"), tag("pbkdf2"));
+    }
+
+    #[test]
+    fn unsupported() {
+        let params = Params::Hkdf {
+            hash: tag("hmac.blake2.b"),
+            salt: vec![],
+            info: vec![],
+        };
+        assert!(matches!(
+            kdf::derive(&params, b"", tag("key.aes.256")),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+        let params = Params::Hkdf {
+            hash: tag("hmac.sha2.256"),
+            salt: vec![],
+            info: vec![],
+        };
+        assert!(matches!(
+            kdf::derive(&params, b"", tag("key.p256.secret")),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+
+    #[test]
+    fn invalid() {
+        // argon2 salts must be at least 8 bytes
+        let params = Params::Argon2 {
+            variant: Argon2Variant::Argon2id,
+            salt: b"salt".to_vec(),
+            memory: 64,
+            iterations: 2,
+            parallelism: 1,
+        };
+        assert!(matches!(
+            kdf::derive(&params, b"password", tag("key.aes.256")),
+            Err(Error::KdfError(_))
+        ));
+
+        // scrypt without its cost parameters
+        let items = [
+            Object::from_type("Kdf.scrypt", &[]).unwrap(),
+            Object::from_type("nonce.bytes", b"salt").unwrap(),
+        ];
+        let obj = Object::list(tag("Kdf.list"), &items);
+        assert!(matches!(Params::from_object(&obj), Err(Error::KdfError(_))));
+
+        // the salt must be a nonce
+        let items = [
+            Object::from_type("Kdf.scrypt", &[4, 1, 1]).unwrap(),
+            Object::from_type("undefined.undefined", b"salt").unwrap(),
+        ];
+        let obj = Object::list(tag("Kdf.list"), &items);
+        assert!(matches!(Params::from_object(&obj), Err(Error::KdfError(_))));
+
+        assert!(matches!(
+            Params::from_object(&items[0]),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+}
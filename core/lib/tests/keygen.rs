@@ -0,0 +1,108 @@
+#![cfg(feature = "keygen")]
+
+mod keygen {
+    use cde::{keygen, Error, Tag};
+
+    fn tag(name: &str) -> Tag {
+        name.parse().unwrap()
+    }
+
+    #[test]
+    fn symmetric() {
+        for (name, len) in &[
+            ("key.aes.128", 16),
+            ("key.aes.256", 32),
+            ("key.chacha20", 32),
+        ] {
+            let key = keygen::generate(tag(name)).unwrap();
+            assert_eq!(*name, key.tag().canonical_name());
+            assert_eq!(*len, key.data().len());
+            assert_ne!(key, keygen::generate(tag(name)).unwrap());
+            assert!(matches!(
+                keygen::public_key(&key),
+                Err(Error::UnsupportedType(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "ed25519")]
+    #[test]
+    fn ed25519() {
+        let secret = keygen::generate(tag("key.ed25519.secret")).unwrap();
+        assert_eq!(32, secret.data().len());
+        let public = keygen::public_key(&secret).unwrap();
+        assert_eq!("key.ed25519.public", public.tag().canonical_name());
+        assert_eq!(32, public.data().len());
+    }
+
+    #[cfg(feature = "x25519")]
+    #[test]
+    fn x25519() {
+        let secret = keygen::generate(tag("key.x25519.secret")).unwrap();
+        let public = keygen::public_key(&secret).unwrap();
+        assert_eq!("key.x25519.public", public.tag().canonical_name());
+        assert_eq!(32, public.data().len());
+    }
+
+    #[cfg(feature = "p256")]
+    #[test]
+    fn p256() {
+        let secret = keygen::generate(tag("key.p256.secret")).unwrap();
+        assert_eq!(32, secret.data().len());
+        let public = keygen::public_key(&secret).unwrap();
+        assert_eq!("key.p256.public", public.tag().canonical_name());
+        assert_eq!(65, public.data().len());
+
+        let signing = keygen::generate(tag("key.p256.signing")).unwrap();
+        let verifying = keygen::public_key(&signing).unwrap();
+        assert_eq!("key.p256.verifying", verifying.tag().canonical_name());
+    }
+
+    #[cfg(feature = "k256")]
+    #[test]
+    fn k256() {
+        let secret = keygen::generate(tag("key.k256.secret")).unwrap();
+        let public = keygen::public_key(&secret).unwrap();
+        assert_eq!("key.k256.public", public.tag().canonical_name());
+        assert_eq!(65, public.data().len());
+
+        let signing = keygen::generate(tag("key.k256.signing")).unwrap();
+        let verifying = keygen::public_key(&signing).unwrap();
+        assert_eq!("key.k256.verifying", verifying.tag().canonical_name());
+    }
+
+    #[cfg(feature = "rsa")]
+    #[test]
+    fn rsa() {
+        use cde::Object;
+
+        let secret =
+            Object::from_type("key.rsa.secret", include_bytes!("data/rsa1024.pkcs1.der")).unwrap();
+        let public = keygen::public_key(&secret).unwrap();
+        assert_eq!(
+            &include_bytes!("data/rsa1024.pub.pkcs1.der")[..],
+            public.data()
+        );
+    }
+
+    #[cfg(not(feature = "ed25519"))]
+    #[test]
+    fn missing_backend() {
+        assert!(matches!(
+            keygen::generate(tag("key.ed25519.secret")),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+    }
+
+    #[test]
+    fn unsupported() {
+        assert!(matches!(
+            keygen::generate(tag("key.bls12381.secret")),
+            Err(Error::UnsupportedType(_))
+        ));
+        assert!(matches!(
+            keygen::generate(tag("key.ed25519.public")),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+}
@@ -0,0 +1,165 @@
+#![cfg(feature = "lock")]
+
+mod locked {
+    use cde::{
+        aead,
+        kdf::{Argon2Variant, Params},
+        locked, Error, Object, TagBuilder,
+    };
+
+    // cheap parameters so the tests run quickly
+    fn params() -> Params {
+        Params::Argon2 {
+            variant: Argon2Variant::Argon2id,
+            salt: b"saltsaltsalt".to_vec(),
+            memory: 64,
+            iterations: 1,
+            parallelism: 1,
+        }
+    }
+
+    fn secret() -> Object {
+        Object::from_type("key.ed25519.secret", &[7; 32]).unwrap()
+    }
+
+    #[test]
+    fn roundtrip() {
+        let obj = locked::lock_with(&secret(), b"hunter2", &params()).unwrap();
+        assert_eq!("key.Locked", obj.tag().canonical_name());
+        assert!(obj.tag().is_exp_sub_class());
+
+        // the secret isn't stored in the clear
+        assert!(!obj.data().windows(32).any(|w| w == [7; 32]));
+
+        let obj = Object::from_encoded(obj.to_string().as_bytes()).unwrap();
+        assert_eq!(secret(), locked::unlock(&obj, b"hunter2").unwrap());
+    }
+
+    #[test]
+    fn layout() {
+        let obj = locked::lock_with(&secret(), b"hunter2", &params()).unwrap();
+        let (kdf, len) = Object::parse(obj.data()).unwrap();
+        assert_eq!(params(), Params::from_object(&kdf).unwrap());
+        let (envelope, rest) = Object::parse(&obj.data()[len..]).unwrap();
+        assert_eq!("aead.list", envelope.tag().canonical_name());
+        assert_eq!(obj.data().len(), len + rest);
+    }
+
+    #[test]
+    fn default_params() {
+        let key = Object::from_type("key.chacha20", &[1; 32]).unwrap();
+        let obj = locked::lock(&key, b"hunter2").unwrap();
+        let kdf = Params::from_object(&Object::parse(obj.data()).unwrap().0).unwrap();
+        assert!(matches!(
+            kdf,
+            Params::Argon2 {
+                variant: Argon2Variant::Argon2id,
+                ..
+            }
+        ));
+        assert_eq!(key, locked::unlock(&obj, b"hunter2").unwrap());
+    }
+
+    #[test]
+    fn wrong_password() {
+        let obj = locked::lock_with(&secret(), b"hunter2", &params()).unwrap();
+        assert!(matches!(
+            locked::unlock(&obj, b"hunter3"),
+            Err(Error::DecryptionFailed)
+        ));
+    }
+
+    #[test]
+    fn not_secret() {
+        let public = Object::from_type("key.ed25519.public", &[7; 32]).unwrap();
+        assert!(matches!(
+            locked::lock_with(&public, b"hunter2", &params()),
+            Err(Error::InvalidKey { .. })
+        ));
+        let nonce = Object::from_type("nonce.bytes", &[7; 32]).unwrap();
+        assert!(matches!(
+            locked::lock_with(&nonce, b"hunter2", &params()),
+            Err(Error::UnsupportedType(_))
+        ));
+        assert!(matches!(
+            locked::unlock(&secret(), b"hunter2"),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+
+    #[test]
+    fn costs_bounded() {
+        let obj = locked::lock_with(&secret(), b"hunter2", &params()).unwrap();
+        let (_, len) = Object::parse(obj.data()).unwrap();
+        let expensive = [
+            Params::Argon2 {
+                variant: Argon2Variant::Argon2id,
+                salt: b"saltsaltsalt".to_vec(),
+                memory: 1 << 24,
+                iterations: 1,
+                parallelism: 1,
+            },
+            Params::Argon2 {
+                variant: Argon2Variant::Argon2id,
+                salt: b"saltsaltsalt".to_vec(),
+                memory: 64,
+                iterations: 1 << 20,
+                parallelism: 1,
+            },
+            Params::Scrypt {
+                salt: b"saltsaltsalt".to_vec(),
+                log_n: 40,
+                r: 8,
+                p: 1,
+            },
+        ];
+        for params in &expensive {
+            let mut data = params.to_object().unwrap().to_bytes();
+            data.extend_from_slice(&obj.data()[len..]);
+            let obj = Object::from_type("key.Locked", &data).unwrap();
+            assert!(matches!(
+                locked::unlock(&obj, b"hunter2"),
+                Err(Error::KdfError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn hkdf_refused() {
+        let hkdf = Params::Hkdf {
+            hash: TagBuilder::from_tag("hmac.sha2.256").build().unwrap(),
+            salt: b"saltsaltsalt".to_vec(),
+            info: Vec::new(),
+        };
+        assert!(matches!(
+            locked::lock_with(&secret(), b"hunter2", &hkdf),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+
+        let obj = locked::lock_with(&secret(), b"hunter2", &params()).unwrap();
+        let (_, len) = Object::parse(obj.data()).unwrap();
+        let mut data = hkdf.to_object().unwrap().to_bytes();
+        data.extend_from_slice(&obj.data()[len..]);
+        let obj = Object::from_type("key.Locked", &data).unwrap();
+        assert!(matches!(
+            locked::unlock(&obj, b"hunter2"),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+    }
+
+    #[test]
+    fn only_xchacha20() {
+        let key = Object::from_type("key.chacha20", &[1; 32]).unwrap();
+        let alg = TagBuilder::from_tag("aead.chacha20-poly1305-ietf")
+            .build()
+            .unwrap();
+        let envelope = aead::seal(&key, alg, &secret().to_bytes(), &[]).unwrap();
+        let mut data = params().to_object().unwrap().to_bytes();
+        data.extend_from_slice(&envelope.to_object().unwrap().to_bytes());
+        let obj = Object::from_type("key.Locked", &data).unwrap();
+        assert!(matches!(
+            locked::unlock(&obj, b"hunter2"),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "minisign")]
+
+mod common;
+
+mod minisign {
+    use crate::common::hex;
+    use cde::{
+        minisign::{PublicKey, SecretKey, Signature},
+        Error, Object,
+    };
+
+    // made from the RFC 8032 test 1 key
+    const PUBLIC: &str = include_str!("data/minisign.pub");
+    const SECRET: &str = include_str!("data/minisign.key");
+    const SIGNATURE: &str = include_str!("data/minisign.minisig");
+
+    // written by rsign2
+    const RSIGN_PUBLIC: &str = include_str!("data/rsign.pub");
+    const RSIGN_SIGNATURE: &str = include_str!("data/rsign.minisig");
+    const RSIGN_ENCRYPTED: &str = include_str!("data/rsign-encrypted.key");
+
+    const ED25519_SECRET: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const ED25519_PUBLIC: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    #[test]
+    fn public_key() {
+        let key: PublicKey = PUBLIC.parse().unwrap();
+        assert_eq!("minisign public key 0123456789ABCDEF", key.comment);
+        assert_eq!(hex("0123456789abcdef"), key.key_id);
+        assert_eq!(
+            Object::from_type("key.ed25519.public", &hex(ED25519_PUBLIC)).unwrap(),
+            key.key
+        );
+        assert_eq!(PUBLIC, key.to_string());
+
+        let key: PublicKey = RSIGN_PUBLIC.parse().unwrap();
+        assert_eq!(RSIGN_PUBLIC, key.to_string());
+    }
+
+    #[test]
+    fn secret_key() {
+        let key: SecretKey = SECRET.parse().unwrap();
+        assert_eq!(hex(ED25519_SECRET), key.secret.data());
+        assert_eq!("key.ed25519.secret", key.secret.tag().canonical_name());
+        assert_eq!(hex(ED25519_PUBLIC), key.public.data());
+        assert_eq!(SECRET, key.to_string());
+        assert_eq!(
+            PUBLIC.parse::<PublicKey>().unwrap().key,
+            key.public_key().key
+        );
+    }
+
+    #[test]
+    fn new_secret_key_round_trip() {
+        let secret = Object::from_type("key.ed25519.secret", &hex(ED25519_SECRET)).unwrap();
+        let public = Object::from_type("key.ed25519.public", &hex(ED25519_PUBLIC)).unwrap();
+        let key = SecretKey::new(secret, public.clone(), [7u8; 8]).unwrap();
+        assert_eq!(key, key.to_string().parse().unwrap());
+
+        let p = PublicKey::new(public, [1, 0, 0, 0, 0, 0, 0, 0xaa]).unwrap();
+        assert_eq!("minisign public key AA00000000000001", p.comment);
+        assert_eq!(p, p.to_string().parse().unwrap());
+    }
+
+    #[test]
+    fn bad_checksum() {
+        // the last characters of the key line are the checksum
+        let tampered = SECRET.replace("NO4=", "MO4=");
+        assert_ne!(SECRET, tampered);
+        assert!(matches!(
+            tampered.parse::<SecretKey>(),
+            Err(Error::MinisignError(_))
+        ));
+    }
+
+    #[test]
+    fn encrypted_secret_key() {
+        assert!(matches!(
+            RSIGN_ENCRYPTED.parse::<SecretKey>(),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+    }
+
+    #[test]
+    fn signature() {
+        for text in [SIGNATURE, RSIGN_SIGNATURE].iter() {
+            let sig: Signature = text.parse().unwrap();
+            assert!(sig.prehashed);
+            assert_eq!("timestamp:1700000000\tfile:msg.txt", sig.trusted_comment);
+            assert_eq!(*text, sig.to_string());
+
+            let obj = sig.to_object().unwrap();
+            assert_eq!("signature.minisign", obj.tag().canonical_name());
+            assert_eq!(text.as_bytes(), obj.data());
+            assert_eq!(sig, Signature::from_object(&obj).unwrap());
+        }
+        let sig: Signature = SIGNATURE.parse().unwrap();
+        assert_eq!(hex("0123456789abcdef"), sig.key_id);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            "RWQ".parse::<PublicKey>(),
+            Err(Error::MinisignError(_))
+        ));
+        assert!(matches!(
+            SIGNATURE
+                .lines()
+                .take(2)
+                .collect::<Vec<_>>()
+                .join("\n")
+                .parse::<Signature>(),
+            Err(Error::MinisignError(_))
+        ));
+        let key = Object::from_type("key.x25519.public", &[0u8; 32]).unwrap();
+        assert!(matches!(
+            PublicKey::new(key, [0u8; 8]),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+}
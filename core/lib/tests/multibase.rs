@@ -0,0 +1,88 @@
+mod multibase {
+    use cde::multibase::{self, Format};
+    use cde::{Error, Object};
+    use data_encoding::{BASE64, BASE64_NOPAD, HEXLOWER, HEXUPPER};
+
+    const ENCODED: &str = "keeA48J-DbADmUdvXrUjJ_r4XkZv4TEtHRVoFS6oQ0AgY5i";
+
+    fn obj() -> Object {
+        ENCODED.parse().unwrap()
+    }
+
+    #[test]
+    fn prefixed() {
+        let s = multibase::encode(&obj());
+        assert_eq!(format!("~{}", ENCODED), s);
+        assert_eq!(
+            (Format::Prefixed, obj()),
+            multibase::detect(s.as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn text() {
+        assert_eq!(
+            (Format::Text, obj()),
+            multibase::detect(ENCODED.as_bytes()).unwrap()
+        );
+        // trailing newlines from files are ignored
+        let s = format!("{}\n", ENCODED);
+        assert_eq!(obj(), multibase::decode(s.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn binary() {
+        let b = obj().to_bytes();
+        assert_eq!((Format::Binary, obj()), multibase::detect(&b).unwrap());
+    }
+
+    #[test]
+    fn hex() {
+        let b = obj().to_bytes();
+        for s in [
+            HEXLOWER.encode(&b),
+            HEXUPPER.encode(&b),
+            format!("0x{}", HEXLOWER.encode(&b)),
+            format!("f{}", HEXLOWER.encode(&b)),
+            format!("F{}", HEXUPPER.encode(&b)),
+        ] {
+            assert_eq!(
+                (Format::Hex, obj()),
+                multibase::detect(s.as_bytes()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn base64() {
+        let o = Object::from_type("nonce.bytes", &[0xfb; 20]).unwrap();
+        let b = o.to_bytes();
+        for s in [
+            BASE64.encode(&b),
+            BASE64_NOPAD.encode(&b),
+            format!("m{}", BASE64_NOPAD.encode(&b)),
+            format!("M{}", BASE64.encode(&b)),
+        ] {
+            assert_eq!(
+                (Format::Base64, o.clone()),
+                multibase::detect(s.as_bytes()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        match multibase::decode(b"not an object!").unwrap_err() {
+            Error::UnknownFormat => {}
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn length_mismatch() {
+        // the data length must account for every byte
+        let mut b = obj().to_bytes();
+        b.push(0);
+        assert!(multibase::decode(&b).is_err());
+    }
+}
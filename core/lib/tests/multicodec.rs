@@ -0,0 +1,97 @@
+mod multicodec {
+    use cde::{multicodec, CryptoData, Error, Object, Tag};
+
+    fn tag(s: &str) -> Tag {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn codes() {
+        assert_eq!(Some(0xed), multicodec::code(&tag("key.ed25519.public")));
+        assert_eq!(Some(0x1306), multicodec::code(&tag("key.p256.secret")));
+        assert_eq!(Some(0x12), multicodec::code(&tag("sha256")));
+        assert_eq!(Some(0xb240), multicodec::code(&tag("digest.blake2.b")));
+        assert_eq!(None, multicodec::code(&tag("claim.oberon")));
+    }
+
+    #[test]
+    fn tags() {
+        assert_eq!(Some(tag("key.x25519.public")), multicodec::tag(0xec));
+        assert_eq!(Some(tag("digest.sha3.256")), multicodec::tag(0x16));
+        assert_eq!(None, multicodec::tag(0x01));
+    }
+
+    #[test]
+    fn code_ignores_length() {
+        let tt = tag("key.ed25519.public").with_data_length(32);
+        assert_eq!(Some(0xed), multicodec::code(&tt));
+    }
+
+    #[test]
+    fn every_code_round_trips() {
+        for code in [
+            0xed, 0xec, 0xe7, 0x1200, 0x1205, 0xeb, 0x1300, 0x1302, 0x1301, 0x1306, 0x1305, 0x130a,
+            0xa0, 0xa2, 0xa4, 0x11, 0x12, 0x13, 0x20, 0x1013, 0x1014, 0x1015, 0x14, 0x15, 0x16,
+            0x17, 0x18, 0x19, 0xd4, 0xd5, 0xb240, 0xb260,
+        ] {
+            let tt = multicodec::tag(code).unwrap();
+            assert_eq!(Some(code), multicodec::code(&tt));
+        }
+    }
+
+    #[test]
+    fn encode() {
+        let key = [7u8; 32];
+        let obj = Object::from_type("ed25519-pub", &key).unwrap();
+        let b = multicodec::encode(&obj).unwrap();
+        assert_eq!(&[0xed, 0x01], &b[..2]);
+        assert_eq!(&key[..], &b[2..]);
+
+        // two byte varint codes
+        let obj = Object::from_type("key.p256.public", &[2u8; 33]).unwrap();
+        let b = multicodec::encode(&obj).unwrap();
+        assert_eq!(&[0x80, 0x24], &b[..2]);
+        assert_eq!(35, b.len());
+    }
+
+    #[test]
+    fn decode() {
+        let mut b = vec![0x12];
+        b.extend_from_slice(&[0xaa; 32]);
+        let obj = multicodec::decode(&b).unwrap();
+        assert_eq!("digest.sha2.256", obj.tag().canonical_name());
+        assert_eq!(32, obj.tag().get_data_length());
+        assert_eq!(&b[1..], obj.data());
+    }
+
+    #[test]
+    fn round_trip() {
+        let obj = Object::from_type("key.x25519.secret", &[9u8; 32]).unwrap();
+        let b = multicodec::encode(&obj).unwrap();
+        assert_eq!(obj, multicodec::decode(&b).unwrap());
+        assert_eq!(obj.len(), 3 + 32);
+    }
+
+    #[test]
+    fn no_code() {
+        let obj = Object::from_type("claim.oberon", &[0u8; 380]).unwrap();
+        match multicodec::encode(&obj).unwrap_err() {
+            Error::NoMulticodec(name) => assert_eq!("claim.oberon", name),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn unknown_code() {
+        match multicodec::decode(&[0x01, 0x00]).unwrap_err() {
+            Error::UnknownMulticodec(code) => assert_eq!(0x01, code),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn truncated_code() {
+        let _obj = multicodec::decode(&[0x80]).unwrap();
+    }
+}
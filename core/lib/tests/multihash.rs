@@ -0,0 +1,120 @@
+mod common;
+
+mod multihash {
+    use crate::common::hex;
+    use cde::{multihash, Error, Object};
+
+    // sha2-256 of "hello world"
+    const HELLO: &str = "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9";
+
+    #[test]
+    fn export_sha256() {
+        let obj = Object::from_type("digest.sha2.256", &hex(HELLO)).unwrap();
+        let mh = multihash::encode(&obj).unwrap();
+        assert_eq!(&[0x12, 0x20], &mh[..2]);
+        assert_eq!(hex(HELLO), &mh[2..]);
+    }
+
+    #[test]
+    fn import_sha256() {
+        let mut mh = vec![0x12, 0x20];
+        mh.extend_from_slice(&hex(HELLO));
+        let obj = multihash::decode(&mh).unwrap();
+        assert_eq!("digest.sha2.256", obj.tag().canonical_name());
+        assert_eq!(hex(HELLO), obj.data());
+    }
+
+    #[test]
+    fn round_trip() {
+        for (name, len) in [
+            ("sha1", 20),
+            ("sha224", 28),
+            ("sha384", 48),
+            ("sha512/256", 32),
+            ("sha3-512", 64),
+            ("shake128", 32),
+            ("md5", 16),
+        ] {
+            let obj = Object::from_type(name, &vec![0x5a; len]).unwrap();
+            let mh = multihash::encode(&obj).unwrap();
+            assert_eq!(obj, multihash::decode(&mh).unwrap());
+        }
+    }
+
+    #[test]
+    fn blake2_sizes() {
+        // blake2b-256 is 0xb220 which is a three byte varint
+        let obj = Object::from_type("blake2b", &[1u8; 32]).unwrap();
+        let mh = multihash::encode(&obj).unwrap();
+        assert_eq!(&[0xa0, 0xe4, 0x02, 0x20], &mh[..4]);
+        assert_eq!(obj, multihash::decode(&mh).unwrap());
+
+        // blake2s-256 is 0xb260
+        let obj = Object::from_type("blake2s", &[1u8; 32]).unwrap();
+        assert_eq!(0xb260, multihash::code(&obj.tag(), 32).unwrap());
+    }
+
+    #[test]
+    fn wrong_length() {
+        let obj = Object::from_type("digest.sha2.256", &[0u8; 20]).unwrap();
+        match multihash::encode(&obj).unwrap_err() {
+            Error::InvalidDigestLength {
+                name,
+                expected,
+                actual,
+            } => {
+                assert_eq!("digest.sha2.256", name);
+                assert_eq!(32, expected);
+                assert_eq!(20, actual);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+
+        let obj = Object::from_type("digest.blake2.s", &[0u8; 64]).unwrap();
+        assert!(multihash::encode(&obj).is_err());
+    }
+
+    #[test]
+    fn wrong_length_import() {
+        let mut mh = vec![0x12, 0x14];
+        mh.extend_from_slice(&[0u8; 20]);
+        match multihash::decode(&mh).unwrap_err() {
+            Error::InvalidDigestLength {
+                expected, actual, ..
+            } => {
+                assert_eq!(32, expected);
+                assert_eq!(20, actual);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn truncated_import() {
+        let mut mh = vec![0x12, 0x20];
+        mh.extend_from_slice(&[0u8; 31]);
+        match multihash::decode(&mh).unwrap_err() {
+            Error::InvalidLength => {}
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn no_multihash() {
+        for name in ["digest.md.2", "digest.md.6", "key.ed25519.public"] {
+            let obj = Object::from_type(name, &[0u8; 16]).unwrap();
+            match multihash::encode(&obj).unwrap_err() {
+                Error::NoMultihash(n) => assert_eq!(name, n),
+                e => panic!("unexpected error: {}", e),
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_code() {
+        match multihash::decode(&[0x00, 0x00]).unwrap_err() {
+            Error::UnknownMultihash(code) => assert_eq!(0, code),
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+mod object {
+    use cde::{CryptoData, Error, Object, TagBuilder, MAX_LIST_DEPTH};
+    use std::convert::TryFrom;
+
+    const ENCODED: &str = "keeA48J-DbADmUdvXrUjJ_r4XkZv4TEtHRVoFS6oQ0AgY5i";
+
+    #[test]
+    fn from_str() {
+        let obj: Object = ENCODED.parse().unwrap();
+        assert_eq!("key.ed25519.secret", obj.tag().canonical_name());
+        assert_eq!(32, obj.data().len());
+        assert_eq!(0x7b, obj.data()[0]);
+        assert_eq!(ENCODED, obj.to_string());
+    }
+
+    #[test]
+    fn new_sets_length() {
+        let tt = TagBuilder::from_tag("claim.oberon").build().unwrap();
+        let obj = Object::new(tt, &[1u8; 380]);
+        assert_eq!(380, obj.tag().get_data_length());
+        assert_eq!(6 + 380, obj.len());
+
+        let mut b = vec![0u8; obj.encode_len()];
+        obj.encode(&mut b);
+        assert!(b.starts_with(b"cod7aAaa"));
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let obj = Object::from_type("nonce.bytes", &[3u8; 200]).unwrap();
+        let b = obj.to_bytes();
+        assert_eq!(obj, Object::try_from(&b[..]).unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn short_data() {
+        let obj = Object::from_type("nonce.bytes", &[3u8; 24]).unwrap();
+        let b = obj.to_bytes();
+        let _obj = Object::from_bytes(&b[..b.len() - 1]).unwrap();
+    }
+
+    #[test]
+    fn trailing_data() {
+        let obj = Object::from_type("nonce.bytes", &[3u8; 24]).unwrap();
+        let mut b = obj.to_bytes();
+        b.push(0);
+        assert!(matches!(Object::from_bytes(&b), Err(Error::InvalidLength)));
+        assert_eq!((obj, b.len() - 1), Object::parse(&b).unwrap());
+    }
+
+    #[test]
+    fn huge_length() {
+        // lengths of 2^63 - 1 and u64::MAX run far past the buffer
+        for last in &[[0x7f, 0x00], [0xff, 0x01]] {
+            let mut b = vec![0x0a, 0x11];
+            b.extend_from_slice(&[0xff; 8]);
+            b.extend_from_slice(last);
+            assert!(matches!(Object::from_bytes(&b), Err(Error::InvalidLength)));
+        }
+    }
+
+    #[test]
+    fn debug() {
+        let obj = Object::from_type("sha1", &[0xab, 0xcd]).unwrap();
+        assert_eq!("digest.sha1 (abcd)", format!("{:?}", obj));
+    }
+
+    #[test]
+    fn list() {
+        let a = Object::from_type("nonce.bytes", &[1u8; 24]).unwrap();
+        let b = Object::from_type("sha1", &[2u8; 20]).unwrap();
+        let tt = TagBuilder::from_tag("undefined.list").build().unwrap();
+        let list = Object::list(tt, &[a.clone(), b.clone()]);
+        assert!(list.is_list());
+        assert_eq!(2, list.tag().get_data_length());
+        assert_eq!(a.len() + b.len(), list.data().len());
+
+        let decoded = Object::from_bytes(&list.to_bytes()).unwrap();
+        assert_eq!(list, decoded);
+        assert_eq!(vec![a, b], decoded.items().unwrap());
+    }
+
+    #[test]
+    fn short_list() {
+        let a = Object::from_type("nonce.bytes", &[1u8; 24]).unwrap();
+        let tt = TagBuilder::from_tag("undefined.list").build().unwrap();
+        let b = Object::list(tt, &[a.clone(), a]).to_bytes();
+        assert!(Object::from_bytes(&b[..b.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn list_count_mismatch() {
+        // Object::new sets the data length to the byte count, not the items
+        let a = Object::from_type("nonce.bytes", &[1u8; 24]).unwrap();
+        let tt = TagBuilder::from_tag("undefined.list").build().unwrap();
+        let list = Object::new(tt, &a.to_bytes());
+        assert!(matches!(list.items(), Err(Error::InvalidLength)));
+    }
+
+    #[test]
+    fn nested_lists() {
+        let tt = TagBuilder::from_tag("undefined.list").build().unwrap();
+        let mut obj = Object::from_type("nonce.bytes", &[1u8; 4]).unwrap();
+        for _ in 0..MAX_LIST_DEPTH {
+            obj = Object::list(tt, &[obj]);
+        }
+        assert_eq!(obj, Object::from_bytes(&obj.to_bytes()).unwrap());
+
+        // one more list is too deep
+        let obj = Object::list(tt, &[obj]);
+        assert!(matches!(
+            Object::from_bytes(&obj.to_bytes()),
+            Err(Error::ListTooDeep(MAX_LIST_DEPTH))
+        ));
+
+        // and a long run of list tags fails without overflowing the stack
+        let tt = tt.with_data_length(1);
+        let mut b = vec![0u8; tt.len()];
+        tt.bytes(&mut b);
+        let b: Vec<u8> = b.iter().cycle().take(b.len() * 300_000).copied().collect();
+        assert!(matches!(Object::from_bytes(&b), Err(Error::ListTooDeep(_))));
+    }
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "p256")]
+
+mod common;
+
+mod p256 {
+    use crate::common::key;
+    use cde::{Error, Object};
+    use std::convert::TryFrom;
+
+    // RFC 9052 C.7.1 "meriadoc.brandybuck@buckland.example"
+    const D: &str = "aff907c99f9ad3aae6c4cdf21122bce2bd68b5283e6907154ad911840fa208cf";
+    const POINT: &str = "0465eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d1e52ed75701163f7f9e40ddf9f341b3dc9ba860af7e0ca7ca7e9eecd0084d19c";
+    const COMPRESSED: &str = "0265eda5a12577c2bae829437fe338701a10aaa375e1bb5b5de108de439c08551d";
+
+    const ZERO: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+    #[test]
+    fn keys() {
+        use p256::{
+            ecdsa::{SigningKey, VerifyingKey},
+            PublicKey, SecretKey,
+        };
+
+        let secret = key("key.p256.secret", D);
+        let sk = SecretKey::try_from(&secret).unwrap();
+        assert_eq!(secret, Object::from(&sk));
+        assert_eq!(
+            key("key.p256.public", POINT),
+            Object::from(&sk.public_key())
+        );
+
+        // the ecdsa keys get the signing and verifying tags
+        let signing = SigningKey::try_from(&secret).unwrap();
+        assert_eq!(key("key.p256.signing", D), Object::from(&signing));
+        assert_eq!(
+            key("key.p256.verifying", POINT),
+            Object::from(signing.verifying_key())
+        );
+
+        // compressed points are written back uncompressed
+        let pk = PublicKey::try_from(&key("key.p256.public", COMPRESSED)).unwrap();
+        assert_eq!(key("key.p256.public", POINT), Object::from(&pk));
+        let vk = VerifyingKey::try_from(&key("key.p256.verifying", POINT)).unwrap();
+        assert_eq!(signing.verifying_key(), &vk);
+    }
+
+    #[test]
+    fn invalid() {
+        use p256::{PublicKey, SecretKey};
+
+        // the scalar must not be zero
+        assert!(matches!(
+            SecretKey::try_from(&key("key.p256.secret", ZERO)),
+            Err(Error::InvalidKey { .. })
+        ));
+
+        // a point not on the curve
+        let mut point = POINT.to_string();
+        point.replace_range(128.., "00");
+        assert!(matches!(
+            PublicKey::try_from(&key("key.p256.public", &point)),
+            Err(Error::InvalidKey { .. })
+        ));
+
+        assert!(matches!(
+            SecretKey::try_from(&key("key.p256.public", POINT)),
+            Err(Error::InvalidKey { .. })
+        ));
+        assert!(matches!(
+            PublicKey::try_from(&key("key.p256.secret", D)),
+            Err(Error::InvalidKey { .. })
+        ));
+        assert!(matches!(
+            SecretKey::try_from(&key("key.k256.secret", D)),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+}
@@ -0,0 +1,103 @@
+mod pem {
+    use cde::{pem, Error, Object};
+
+    const ENCODED: &str = "keeA48J-DbADmUdvXrUjJ_r4XkZv4TEtHRVoFS6oQ0AgY5i";
+
+    #[test]
+    fn label() {
+        let obj: Object = ENCODED.parse().unwrap();
+        assert_eq!("CDE KEY ED25519 SECRET", pem::label(&obj.tag()));
+        let obj = Object::from_type("sha512/256", &[0u8; 32]).unwrap();
+        assert_eq!("CDE DIGEST SHA2 512/256", pem::label(&obj.tag()));
+    }
+
+    #[test]
+    fn experimental_label() {
+        let std = Object::from_type("aead.aes256-gcm", &[1u8; 16]).unwrap();
+        let exp = Object::from_type("Aead.aes256-gcm", &[1u8; 16]).unwrap();
+        assert_eq!("CDE AEAD AES256-GCM", pem::label(&std.tag()));
+        assert_eq!("CDE AEAD AES256-GCM", pem::label(&exp.tag()));
+
+        // the tag inside says which one it is
+        let obj = pem::decode(&pem::encode(&exp)).unwrap();
+        assert!(obj.tag().is_exp_class());
+        assert_eq!(exp, obj);
+    }
+
+    #[test]
+    fn label_ignores_case() {
+        let s = format!(
+            "-----BEGIN CDE key ed25519 secret-----\n{}\n-----END CDE Key Ed25519 Secret-----\n",
+            ENCODED
+        );
+        assert_eq!(ENCODED, pem::decode(&s).unwrap().to_string());
+    }
+
+    #[test]
+    fn encode() {
+        let obj: Object = ENCODED.parse().unwrap();
+        assert_eq!(
+            format!(
+                "-----BEGIN CDE KEY ED25519 SECRET-----\n{}\n-----END CDE KEY ED25519 SECRET-----\n",
+                ENCODED
+            ),
+            pem::encode(&obj)
+        );
+    }
+
+    #[test]
+    fn line_wrap() {
+        let obj = Object::from_type("claim.oberon", &[0x42; 380]).unwrap();
+        let s = pem::encode(&obj);
+        let body: Vec<&str> = s.lines().filter(|l| !l.starts_with("-----")).collect();
+        assert!(body.len() > 1);
+        assert!(body.iter().all(|l| l.len() <= 64));
+        assert!(body[..body.len() - 1].iter().all(|l| l.len() == 64));
+        assert_eq!(obj, pem::decode(&s).unwrap());
+    }
+
+    #[test]
+    fn round_trip_with_surrounding_text() {
+        let obj = Object::from_type("x25519-pub", &[7u8; 32]).unwrap();
+        let s = format!("a public key:\n\n{}\ntrailing", pem::encode(&obj));
+        assert_eq!(obj, pem::decode(&s).unwrap());
+    }
+
+    #[test]
+    fn label_mismatch() {
+        let s = format!(
+            "-----BEGIN CDE KEY ED25519 PUBLIC-----\n{}\n-----END CDE KEY ED25519 PUBLIC-----\n",
+            ENCODED
+        );
+        match pem::decode(&s).unwrap_err() {
+            Error::PemLabelMismatch { label, name } => {
+                assert_eq!("CDE KEY ED25519 PUBLIC", label);
+                assert_eq!("key.ed25519.secret", name);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn missing_end() {
+        let s = format!("-----BEGIN CDE KEY ED25519 SECRET-----\n{}\n", ENCODED);
+        match pem::decode(&s).unwrap_err() {
+            Error::InvalidPem(_) => {}
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+
+    #[test]
+    fn end_label_differs() {
+        let s = format!(
+            "-----BEGIN CDE KEY ED25519 SECRET-----\n{}\n-----END CDE KEY-----\n",
+            ENCODED
+        );
+        assert!(pem::decode(&s).is_err());
+    }
+
+    #[test]
+    fn not_pem() {
+        assert!(pem::decode(ENCODED).is_err());
+    }
+}
@@ -0,0 +1,114 @@
+mod pgp {
+    use cde::{pgp, Error, Object};
+
+    // detached signatures made by gpg
+    const ED25519_ASC: &str = include_str!("data/ed25519.pgp.asc");
+    const ED25519_SIG: &[u8] = include_bytes!("data/ed25519.pgp.sig");
+    const RSA_SIG: &[u8] = include_bytes!("data/rsa2048.pgp.sig");
+
+    #[test]
+    fn armored_round_trip() {
+        let obj = pgp::from_armor(ED25519_ASC).unwrap();
+        assert_eq!("signature.pgp", obj.tag().canonical_name());
+        assert_eq!(0x88, obj.data()[0]);
+        assert_eq!(ED25519_ASC, pgp::to_armor(&obj).unwrap());
+    }
+
+    #[test]
+    fn binary() {
+        let obj = pgp::from_binary(ED25519_SIG).unwrap();
+        assert_eq!(ED25519_SIG, obj.data());
+        let armored = pgp::to_armor(&obj).unwrap();
+        assert_eq!(obj, pgp::from_armor(&armored).unwrap());
+    }
+
+    #[test]
+    fn inspect() {
+        let info = pgp::inspect(&pgp::from_binary(ED25519_SIG).unwrap()).unwrap();
+        assert_eq!(1, info.len());
+        assert_eq!("Signature", pgp::packet_name(info[0].tag));
+        assert_eq!(4, info[0].version);
+        assert_eq!(0, info[0].signature_type);
+        assert_eq!(22, info[0].public_key_algorithm);
+        assert_eq!(8, info[0].hash_algorithm);
+        assert_eq!(
+            Some([0x90, 0xca, 0x35, 0xa1, 0xe6, 0xad, 0xb8, 0xb4]),
+            info[0].issuer
+        );
+
+        // a two octet old format length
+        let info = pgp::inspect(&pgp::from_binary(RSA_SIG).unwrap()).unwrap();
+        assert_eq!(1, info[0].public_key_algorithm);
+        assert_eq!(
+            Some([0xb7, 0x4d, 0x7d, 0xf8, 0xf1, 0x42, 0x8b, 0xee]),
+            info[0].issuer
+        );
+    }
+
+    #[test]
+    fn new_format_and_several_packets() {
+        let mut b = vec![0xc2, ED25519_SIG[1]];
+        b.extend_from_slice(&ED25519_SIG[2..]);
+        b.extend_from_slice(RSA_SIG);
+        let info = pgp::inspect(&pgp::from_binary(&b).unwrap()).unwrap();
+        assert_eq!(2, info.len());
+        assert_eq!(22, info[0].public_key_algorithm);
+        assert_eq!(1, info[1].public_key_algorithm);
+    }
+
+    #[test]
+    fn armor_headers_and_no_checksum() {
+        let armored: String = ED25519_ASC
+            .replacen("\n\n", "\nComment: archived\n\n", 1)
+            .lines()
+            .filter(|l| !l.starts_with('='))
+            .map(|l| format!("{}\r\n", l))
+            .collect();
+        assert_eq!(
+            pgp::from_armor(ED25519_ASC).unwrap(),
+            pgp::from_armor(&armored).unwrap()
+        );
+    }
+
+    #[test]
+    fn bad_checksum() {
+        let armored = ED25519_ASC.replace("=wkrN", "=wkrM");
+        assert!(matches!(
+            pgp::from_armor(&armored),
+            Err(Error::InvalidPgp(_))
+        ));
+    }
+
+    #[test]
+    fn bad_framing() {
+        // a user id packet
+        assert!(matches!(
+            pgp::from_binary(b"\xb4\x03cde"),
+            Err(Error::InvalidPgp(_))
+        ));
+        assert!(matches!(
+            pgp::from_binary(&ED25519_SIG[..ED25519_SIG.len() - 1]),
+            Err(Error::InvalidPgp(_))
+        ));
+        assert!(matches!(
+            pgp::from_binary(&[ED25519_SIG, &[0u8][..]].concat()),
+            Err(Error::InvalidPgp(_))
+        ));
+        assert!(matches!(pgp::from_binary(&[]), Err(Error::InvalidPgp(_))));
+
+        // a new format signature packet claiming the largest body length
+        assert!(matches!(
+            pgp::from_binary(b"\xc2\xff\xff\xff\xff\xff\x04"),
+            Err(Error::InvalidPgp(_))
+        ));
+    }
+
+    #[test]
+    fn wrong_type() {
+        let obj = Object::from_type("nonce.bytes", ED25519_SIG).unwrap();
+        assert!(matches!(
+            pgp::to_armor(&obj),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "rsa")]
+
+mod rsa {
+    use cde::{Error, Object};
+    use rsa::{traits::PublicKeyParts, RsaPrivateKey, RsaPublicKey};
+    use std::convert::TryFrom;
+
+    // generated by openssl
+    const SECRET: &[u8] = include_bytes!("data/rsa1024.pkcs1.der");
+    const PUBLIC: &[u8] = include_bytes!("data/rsa1024.pub.pkcs1.der");
+
+    #[test]
+    fn private_key() {
+        let secret = Object::from_type("key.rsa.secret", SECRET).unwrap();
+        let key = RsaPrivateKey::try_from(&secret).unwrap();
+        assert_eq!(1024, key.n().bits());
+        assert_eq!(secret, Object::try_from(&key).unwrap());
+        assert_eq!(
+            Object::from_type("key.rsa.public", PUBLIC).unwrap(),
+            Object::try_from(&key.to_public_key()).unwrap()
+        );
+    }
+
+    #[test]
+    fn public_key() {
+        let public = Object::from_type("key.rsa.public", PUBLIC).unwrap();
+        let key = RsaPublicKey::try_from(&public).unwrap();
+        assert_eq!(public, Object::try_from(&key).unwrap());
+    }
+
+    #[test]
+    fn invalid() {
+        let public = Object::from_type("key.rsa.public", PUBLIC).unwrap();
+        assert!(matches!(
+            RsaPrivateKey::try_from(&public),
+            Err(Error::InvalidKey { .. })
+        ));
+        let secret = Object::from_type("key.rsa.secret", &SECRET[..SECRET.len() - 1]).unwrap();
+        assert!(matches!(
+            RsaPrivateKey::try_from(&secret),
+            Err(Error::InvalidKey { .. })
+        ));
+        let public = Object::from_type("key.ed25519.public", PUBLIC).unwrap();
+        assert!(matches!(
+            RsaPublicKey::try_from(&public),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+}
@@ -0,0 +1,217 @@
+mod setters {
+    use cde::{idx, CryptoData, Error, Reason, Tag, TagBuilder};
+
+    fn tag(s: &str) -> Tag {
+        TagBuilder::from_tag(s).build().unwrap()
+    }
+
+    fn bytes(tt: &Tag) -> Vec<u8> {
+        let mut b = vec![0u8; tt.len()];
+        tt.bytes(&mut b);
+        b
+    }
+
+    fn encoded(tt: &Tag) -> String {
+        let mut b = vec![0u8; tt.encode_len()];
+        tt.encode(&mut b);
+        String::from_utf8(b).unwrap()
+    }
+
+    #[test]
+    fn set_class() {
+        let mut tt = TagBuilder::from_tag("key.undefined").build().unwrap();
+        tt.set_class(idx('n')).unwrap();
+        assert_eq!(tag("nonce.undefined"), tt);
+
+        // 'z' isn't a registered class and isn't experimental
+        assert!(matches!(
+            tt.set_class(idx('z')),
+            Err(Error::InvalidClass {
+                reason: Reason::NotExperimental,
+                ..
+            })
+        ));
+        assert_eq!(tag("nonce.undefined"), tt);
+    }
+
+    #[test]
+    fn set_subclass() {
+        let mut tt = TagBuilder::from_tag("key.ed25519.public").build().unwrap();
+        tt.set_subclass(idx('x')).unwrap();
+        assert_eq!(tag("key.x25519.public"), tt);
+
+        // a standard class only takes its registered sub-classes
+        assert!(matches!(
+            tt.set_subclass(idx('Z')),
+            Err(Error::InvalidSubClass {
+                reason: Reason::ParentNotExperimental,
+                ..
+            })
+        ));
+        assert_eq!(tag("key.x25519.public"), tt);
+    }
+
+    #[test]
+    fn set_subsubclass() {
+        let mut tt = TagBuilder::from_tag("key.ed25519.public").build().unwrap();
+        tt.set_subsubclass(1).unwrap();
+        assert_eq!(tag("key.ed25519.secret"), tt);
+
+        let mut tt = TagBuilder::from_tag("key.chacha20").build().unwrap();
+        assert!(matches!(
+            tt.set_subsubclass(3),
+            Err(Error::InvalidSubSubClass {
+                reason: Reason::NotAllowed,
+                ..
+            })
+        ));
+        assert!(matches!(
+            tt.set_subsubclass(16),
+            Err(Error::InvalidSubSubClass {
+                reason: Reason::OutOfRange,
+                ..
+            })
+        ));
+        assert_eq!(0, tt.subsubclass());
+    }
+
+    #[test]
+    fn validated_builders() {
+        let tt = TagBuilder::from_tag("undefined.undefined")
+            .build()
+            .unwrap()
+            .with_class(idx('k'))
+            .unwrap()
+            .with_subclass(idx('e'))
+            .unwrap()
+            .with_subsubclass(1)
+            .unwrap()
+            .with_data_length(32);
+        assert_eq!(
+            TagBuilder::from_tag("key.ed25519.secret")
+                .build()
+                .unwrap()
+                .with_data_length(32),
+            tt
+        );
+        assert!(tt.with_class(idx('z')).is_err());
+    }
+
+    #[test]
+    fn set_raw_class() {
+        let mut tt = TagBuilder::from_tag("key.ed25519.public").build().unwrap();
+        tt.set_raw_class(idx('s')).unwrap();
+        assert_eq!(idx('s'), tt.class());
+        assert_eq!(idx('e'), tt.subclass());
+        assert_eq!(0, tt.subsubclass());
+    }
+
+    #[test]
+    fn set_raw_subclass() {
+        let mut tt = TagBuilder::from_tag("key.ed25519.secret").build().unwrap();
+        tt.set_raw_subclass(idx('X')).unwrap();
+        assert_eq!(idx('k'), tt.class());
+        assert_eq!(idx('X'), tt.subclass());
+        assert_eq!(1, tt.subsubclass());
+        assert!(tt.is_exp_sub_class());
+    }
+
+    #[test]
+    fn set_raw_subsubclass() {
+        let mut tt = TagBuilder::from_tag("list.list").build().unwrap();
+        tt.set_raw_subsubclass(15).unwrap();
+        assert_eq!(idx('-'), tt.class());
+        assert_eq!(idx('-'), tt.subclass());
+        assert_eq!(15, tt.subsubclass());
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_raw_class_out_of_range() {
+        let mut tt = Tag::default();
+        tt.set_raw_class(64).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_raw_subclass_out_of_range() {
+        let mut tt = Tag::default();
+        tt.set_raw_subclass(64).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_raw_subsubclass_out_of_range() {
+        let mut tt = Tag::default();
+        tt.set_raw_subsubclass(16).unwrap();
+    }
+
+    #[test]
+    fn with_builders() {
+        let tt = Tag::default()
+            .with_raw_class(idx('k'))
+            .unwrap()
+            .with_raw_subclass(idx('e'))
+            .unwrap()
+            .with_raw_subsubclass(1)
+            .unwrap()
+            .with_data_length(32);
+        assert_eq!(
+            TagBuilder::from_tag("key.ed25519.secret")
+                .build()
+                .unwrap()
+                .with_data_length(32),
+            tt
+        );
+        assert_eq!("keeA", encoded(&tt));
+    }
+
+    #[test]
+    fn clear_exp_class_keeps_class_bits() {
+        // 'c' (claim) has the bit set that used to be cleared by mistake
+        let mut tt = TagBuilder::from_tag("Claim.Oberon").build().unwrap();
+        assert!(tt.is_exp_class());
+        tt.set_exp_class(false);
+        assert!(!tt.is_exp_class());
+        assert_eq!(idx('c'), tt.class());
+        assert_eq!("claim.Oberon.0", format!("{}", tt));
+    }
+
+    #[test]
+    fn toggle_exp_class_round_trip() {
+        let orig = TagBuilder::from_tag("claim.Oberon")
+            .build()
+            .unwrap()
+            .with_data_length(760);
+        let exp = orig.with_exp_class(true);
+        assert_eq!(idx('C'), exp.class());
+        assert_ne!(bytes(&orig), bytes(&exp));
+        assert_ne!(encoded(&orig), encoded(&exp));
+
+        let back = exp.with_exp_class(false);
+        assert_eq!(orig, back);
+        assert_eq!(bytes(&orig), bytes(&back));
+        assert_eq!(encoded(&orig), encoded(&back));
+        assert_eq!(orig, TagBuilder::from_bytes(&bytes(&back)).build().unwrap());
+        assert_eq!(orig, Tag::from_encoded(encoded(&back).as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn toggle_exp_sub_class_round_trip() {
+        let orig = TagBuilder::from_tag("key.ed25519.public")
+            .build()
+            .unwrap()
+            .with_data_length(32);
+        let exp = orig.with_exp_sub_class(true);
+        assert_eq!(idx('E'), exp.subclass());
+        assert_eq!("keaA", encoded(&orig));
+        assert_eq!("kEaA", encoded(&exp));
+
+        let back = exp.with_exp_sub_class(false);
+        assert_eq!(orig, back);
+        assert_eq!(bytes(&orig), bytes(&back));
+        assert_eq!(encoded(&orig), encoded(&back));
+        assert_eq!(orig, TagBuilder::from_bytes(&bytes(&back)).build().unwrap());
+        assert_eq!(orig, Tag::from_encoded(encoded(&back).as_bytes()).unwrap());
+    }
+}
@@ -0,0 +1,148 @@
+#![cfg(any(
+    feature = "ed25519",
+    feature = "k256",
+    feature = "p256",
+    feature = "rsa"
+))]
+
+mod common;
+
+mod signature {
+    use crate::common::hex;
+    use cde::{signature, Error, Object};
+
+    fn obj(name: &str, data: &str) -> Object {
+        Object::from_type(name, &hex(data)).unwrap()
+    }
+
+    #[cfg(feature = "ed25519")]
+    mod ed25519 {
+        use super::*;
+
+        // RFC 8032 test 2
+        const SECRET: &str = "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb";
+        const PUBLIC: &str = "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c";
+        const SIGNATURE: &str = "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00";
+
+        #[test]
+        fn sign() {
+            let sig = signature::sign(&obj("key.ed25519.secret", SECRET), &[0x72]).unwrap();
+            assert_eq!(obj("signature.Ed25519", SIGNATURE), sig);
+        }
+
+        #[test]
+        fn verify() {
+            let public = obj("key.ed25519.public", PUBLIC);
+            let sig = obj("signature.Ed25519", SIGNATURE);
+            signature::verify(&public, &sig, &[0x72]).unwrap();
+            assert!(matches!(
+                signature::verify(&public, &sig, &[0x73]),
+                Err(Error::BadSignature)
+            ));
+        }
+
+        #[test]
+        fn malformed() {
+            let public = obj("key.ed25519.public", PUBLIC);
+            let sig = obj("signature.Ed25519", &SIGNATURE[2..]);
+            assert!(matches!(
+                signature::verify(&public, &sig, &[0x72]),
+                Err(Error::InvalidSignature(_))
+            ));
+            let sig = obj("signature.Ecdsa.p256", SIGNATURE);
+            assert!(matches!(
+                signature::verify(&public, &sig, &[0x72]),
+                Err(Error::UnsupportedType(_))
+            ));
+            assert!(matches!(
+                signature::sign(&public, &[0x72]),
+                Err(Error::InvalidKey { .. })
+            ));
+        }
+    }
+
+    #[cfg(feature = "p256")]
+    mod p256 {
+        use super::*;
+
+        // RFC 6979 A.2.5 with SHA-256 and the message "sample"
+        const SECRET: &str = "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721";
+        const PUBLIC: &str = "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299";
+        const SIGNATURE: &str = "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8";
+
+        #[test]
+        fn sign() {
+            let sig = signature::sign(&obj("key.p256.secret", SECRET), b"sample").unwrap();
+            assert_eq!(obj("signature.Ecdsa.p256", SIGNATURE), sig);
+            let sig = signature::sign(&obj("key.p256.signing", SECRET), b"sample").unwrap();
+            assert_eq!(obj("signature.Ecdsa.p256", SIGNATURE), sig);
+        }
+
+        #[test]
+        fn verify() {
+            let sig = obj("signature.Ecdsa.p256", SIGNATURE);
+            for name in &["key.p256.public", "key.p256.verifying"] {
+                let public = obj(name, PUBLIC);
+                signature::verify(&public, &sig, b"sample").unwrap();
+                assert!(matches!(
+                    signature::verify(&public, &sig, b"test"),
+                    Err(Error::BadSignature)
+                ));
+            }
+        }
+    }
+
+    #[cfg(feature = "k256")]
+    #[test]
+    fn k256() {
+        let secret = obj(
+            "key.k256.secret",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        );
+        let public = obj("key.k256.public", "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8");
+        let sig = signature::sign(&secret, b"sample").unwrap();
+        assert_eq!("signature.Ecdsa.k256", sig.tag().canonical_name());
+        signature::verify(&public, &sig, b"sample").unwrap();
+        assert!(matches!(
+            signature::verify(&public, &sig, b"test"),
+            Err(Error::BadSignature)
+        ));
+        let sig = Object::from_type("signature.Ecdsa.p256", sig.data()).unwrap();
+        assert!(matches!(
+            signature::verify(&public, &sig, b"sample"),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+
+    #[cfg(feature = "rsa")]
+    #[test]
+    fn rsa() {
+        let secret =
+            Object::from_type("key.rsa.secret", include_bytes!("data/rsa1024.pkcs1.der")).unwrap();
+        let public = Object::from_type(
+            "key.rsa.public",
+            include_bytes!("data/rsa1024.pub.pkcs1.der"),
+        )
+        .unwrap();
+        let sig = signature::sign(&secret, b"sample").unwrap();
+        assert_eq!("signature.Rsa", sig.tag().canonical_name());
+        assert_eq!(128, sig.data().len());
+        signature::verify(&public, &sig, b"sample").unwrap();
+        assert!(matches!(
+            signature::verify(&public, &sig, b"test"),
+            Err(Error::BadSignature)
+        ));
+    }
+
+    #[test]
+    fn unsupported() {
+        let secret = obj(
+            "key.x25519.secret",
+            "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a",
+        );
+        assert!(matches!(
+            signature::sign(&secret, b"sample"),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "x25519")]
+
+mod common;
+
+mod x25519 {
+    use crate::common::{hex, key};
+    use cde::{Error, Object};
+    use std::convert::TryFrom;
+    use x25519_dalek::{PublicKey, StaticSecret};
+
+    // RFC 7748 section 6.1
+    const ALICE_SECRET: &str = "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a";
+    const ALICE_PUBLIC: &str = "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a";
+    const BOB_PUBLIC: &str = "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f";
+    const SHARED: &str = "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742";
+
+    #[test]
+    fn static_secret() {
+        let secret = key("key.x25519.secret", ALICE_SECRET);
+        let alice = StaticSecret::try_from(&secret).unwrap();
+        assert_eq!(
+            key("key.x25519.public", ALICE_PUBLIC),
+            Object::from(&PublicKey::from(&alice))
+        );
+        assert_eq!(secret, Object::from(&alice));
+    }
+
+    #[test]
+    fn public_key() {
+        let bob = PublicKey::try_from(&key("key.x25519.public", BOB_PUBLIC)).unwrap();
+        let alice = StaticSecret::try_from(&key("key.x25519.secret", ALICE_SECRET)).unwrap();
+        assert_eq!(hex(SHARED), alice.diffie_hellman(&bob).as_bytes());
+    }
+
+    #[test]
+    fn wrong_half() {
+        let public = key("key.x25519.public", ALICE_PUBLIC);
+        assert!(matches!(
+            StaticSecret::try_from(&public),
+            Err(Error::InvalidKey { .. })
+        ));
+    }
+
+    #[test]
+    fn wrong_type() {
+        let public = key("key.ed25519.public", ALICE_PUBLIC);
+        assert!(matches!(
+            PublicKey::try_from(&public),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+
+    #[test]
+    fn wrong_size() {
+        let public = key("key.x25519.public", &ALICE_PUBLIC[..62]);
+        assert!(matches!(
+            PublicKey::try_from(&public),
+            Err(Error::InvalidKey { .. })
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "x509")]
+
+mod common;
+
+mod x509 {
+    use crate::common::hex;
+    use cde::{x509::X509Certificate, Error, Object};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    // self-signed ed25519 CA certificate generated by openssl
+    const CA_PEM: &str = include_str!("data/ed25519.crt.pem");
+    const CA_KEY: &str = "58a005392021f6baf1d3657b53e632e7630b4f312030f0cb951d7e68f02b8198";
+
+    // p256 certificate signed by the CA
+    const LEAF_DER: &[u8] = include_bytes!("data/p256.crt.der");
+    const LEAF_KEY: &str = "0466c69a74716daedea0f99f11f99e5602850f8b8abbdd4dac2d8d44b5b4a16efb1a62dcc56f6cecb01d8c4642b5424edcae68849afebf3d18d0cf9d861daec8e0";
+
+    // self-signed p384 certificate
+    const P384_DER: &[u8] = include_bytes!("data/p384.crt.der");
+
+    #[test]
+    fn pem() {
+        let cert = X509Certificate::from_pem(CA_PEM).unwrap();
+        assert_eq!("CN=CDE Test CA,O=Cryptid", cert.subject());
+        assert_eq!(cert.subject(), cert.issuer());
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_secs(1792374767),
+            cert.not_before()
+        );
+        assert_eq!(
+            UNIX_EPOCH + Duration::from_secs(2107734767),
+            cert.not_after()
+        );
+        assert_eq!(CA_PEM, cert.to_pem());
+
+        let key = cert.public_key().unwrap();
+        assert_eq!("key.ed25519.public", key.tag().canonical_name());
+        assert_eq!(hex(CA_KEY), key.data());
+    }
+
+    #[test]
+    fn der() {
+        let cert = X509Certificate::from_der(LEAF_DER).unwrap();
+        assert_eq!("CN=leaf.example.com", cert.subject());
+        assert_eq!("CN=CDE Test CA,O=Cryptid", cert.issuer());
+        assert_eq!(LEAF_DER, cert.der());
+
+        let key = cert.public_key().unwrap();
+        assert_eq!("key.p256.public", key.tag().canonical_name());
+        assert_eq!(hex(LEAF_KEY), key.data());
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            X509Certificate::from_pem(CA_PEM).unwrap(),
+            X509Certificate::parse(CA_PEM.as_bytes()).unwrap()
+        );
+        assert_eq!(LEAF_DER, X509Certificate::parse(LEAF_DER).unwrap().der());
+    }
+
+    #[test]
+    fn object() {
+        let cert = X509Certificate::from_der(LEAF_DER).unwrap();
+        let obj = cert.to_object().unwrap();
+        assert_eq!("signature.x509", obj.tag().canonical_name());
+        assert_eq!(LEAF_DER, obj.data());
+
+        let obj = Object::from_encoded(obj.to_string().as_bytes()).unwrap();
+        assert_eq!(cert, X509Certificate::from_object(&obj).unwrap());
+    }
+
+    #[test]
+    fn unsupported_key() {
+        // the certificate parses but the key can't be a CDE key
+        let cert = X509Certificate::from_der(P384_DER).unwrap();
+        assert_eq!("CN=p384", cert.subject());
+        assert!(matches!(
+            cert.public_key(),
+            Err(Error::UnsupportedAlgorithm(_))
+        ));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(matches!(
+            X509Certificate::from_der(&LEAF_DER[..LEAF_DER.len() - 1]),
+            Err(Error::InvalidCertificate(_))
+        ));
+        assert!(matches!(
+            X509Certificate::from_der(&hex(CA_KEY)),
+            Err(Error::InvalidCertificate(_))
+        ));
+        assert!(matches!(
+            X509Certificate::from_pem("-----BEGIN CERTIFICATE-----\n"),
+            Err(Error::InvalidCertificate(_))
+        ));
+    }
+
+    #[test]
+    fn wrong_type() {
+        let obj = Object::from_type("signature.pgp", LEAF_DER).unwrap();
+        assert!(matches!(
+            X509Certificate::from_object(&obj),
+            Err(Error::UnsupportedType(_))
+        ));
+    }
+}